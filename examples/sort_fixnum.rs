@@ -0,0 +1,39 @@
+// Compares `Array::sort_fixnum()` with the generic `Array::sort()`
+//
+//     cargo run --release --example sort_fixnum
+
+extern crate ruru;
+
+use std::time::{Duration, Instant};
+
+use ruru::{Array, Fixnum, Object, VM};
+
+const LENGTH: i64 = 100_000;
+const RUNS: u32 = 10;
+
+fn measure<F: Fn() -> Array>(sort: F) -> Duration {
+    let start = Instant::now();
+
+    for _ in 0..RUNS {
+        sort();
+    }
+
+    start.elapsed() / RUNS
+}
+
+fn main() {
+    VM::init();
+
+    // A deterministic permutation of `0..LENGTH`
+    let array: Array = (0..LENGTH)
+        .map(|number| Fixnum::new(number * 7919 % LENGTH).to_any_object())
+        .collect();
+
+    assert!(array.sort().equals(&array.sort_fixnum().unwrap()));
+
+    let generic = measure(|| array.sort());
+    let fixnum = measure(|| array.sort_fixnum().unwrap());
+
+    println!("Array::sort():        {:?}", generic);
+    println!("Array::sort_fixnum(): {:?}", fixnum);
+}
@@ -0,0 +1,89 @@
+//! Custom derive for Ruby objects in [ruru](https://github.com/d-unseductable/ruru)
+//!
+//! This crate is used by `ruru` when the `derive` feature is enabled, there is no need to depend
+//! on it directly.
+
+extern crate proc_macro;
+extern crate syn;
+#[macro_use]
+extern crate quote;
+
+use proc_macro::TokenStream;
+
+/// Implements `From<Value>`, `Object`, `VerifiedObject` and `From<_> for AnyObject` for a struct
+///
+/// Generates the same code as `class!` macro, but the struct is written by hand, so other traits
+/// can be derived for it and other fields can be added next to the `value: Value` field.
+///
+/// See the documentation for `ruru::RuruObject` for an example.
+#[proc_macro_derive(RuruObject)]
+pub fn ruru_object(input: TokenStream) -> TokenStream {
+    let source = input.to_string();
+    let ast = syn::parse_derive_input(&source).unwrap();
+
+    impl_ruru_object(&ast).parse().unwrap()
+}
+
+fn impl_ruru_object(ast: &syn::DeriveInput) -> quote::Tokens {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let fields = match ast.body {
+        syn::Body::Struct(syn::VariantData::Struct(ref fields)) => fields,
+        _ => panic!("#[derive(RuruObject)] is only defined for structs with named fields"),
+    };
+
+    let field_names: Vec<&syn::Ident> = fields
+        .iter()
+        .filter_map(|field| field.ident.as_ref())
+        .collect();
+
+    if !field_names.iter().any(|ident| ident.as_ref() == "value") {
+        panic!("#[derive(RuruObject)] requires a `value: Value` field");
+    }
+
+    let other_fields: Vec<&syn::Ident> = field_names
+        .into_iter()
+        .filter(|ident| ident.as_ref() != "value")
+        .collect();
+
+    quote! {
+        impl #impl_generics ::std::convert::From<::ruru::types::Value> for #name #ty_generics
+            #where_clause
+        {
+            fn from(value: ::ruru::types::Value) -> Self {
+                #name {
+                    value: value,
+                    #(#other_fields: ::std::default::Default::default(),)*
+                }
+            }
+        }
+
+        impl #impl_generics ::ruru::Object for #name #ty_generics #where_clause {
+            #[inline]
+            fn value(&self) -> ::ruru::types::Value {
+                self.value
+            }
+        }
+
+        impl #impl_generics ::ruru::VerifiedObject for #name #ty_generics #where_clause {
+            fn is_correct_type<O: ::ruru::Object>(object: &O) -> bool {
+                ::ruru::Class::try_from_existing(stringify!(#name))
+                    .map(|class| object.is_a(&class))
+                    .unwrap_or(false)
+            }
+
+            fn error_message() -> &'static str {
+                concat!("Error converting to ", stringify!(#name))
+            }
+        }
+
+        impl #impl_generics ::std::convert::From<#name #ty_generics> for ::ruru::AnyObject
+            #where_clause
+        {
+            fn from(object: #name #ty_generics) -> Self {
+                ::ruru::Object::to_any_object(&object)
+            }
+        }
+    }
+}
@@ -1,7 +1,14 @@
 use ruby_sys::array;
 
+use binding::{fixnum, hash};
+use binding::util as binding_util;
 use types::{c_long, Value};
 
+extern "C" {
+    // `ruby-sys` does not provide bindings for subsequences of arrays
+    fn rb_ary_subseq(array: Value, begin: c_long, length: c_long) -> Value;
+}
+
 pub fn new() -> Value {
     unsafe { array::rb_ary_new() }
 }
@@ -65,3 +72,69 @@ pub fn sort(array: Value) -> Value {
 pub fn sort_bang(array: Value) -> Value {
     unsafe { array::rb_ary_sort_bang(array) }
 }
+
+pub fn sum(array: Value) -> Value {
+    binding_util::call_method(array, "sum", None)
+}
+
+// `Array#sum` is defined in Ruby 2.4 and newer, elements are added with `+` starting from `0`
+pub fn sum_fallback(array: Value) -> Value {
+    let mut result = fixnum::int_to_num(0);
+
+    for offset in 0..len(array) {
+        let item = entry(array, offset);
+
+        result = binding_util::call_method(result, "+", Some(vec![item]));
+    }
+
+    result
+}
+
+pub fn min(array: Value) -> Value {
+    binding_util::call_method(array, "min", None)
+}
+
+pub fn max(array: Value) -> Value {
+    binding_util::call_method(array, "max", None)
+}
+
+// `count` must not be negative
+pub fn take(array: Value, count: i64) -> Value {
+    unsafe { rb_ary_subseq(array, 0, count as c_long) }
+}
+
+// `count` must not be negative. `rb_ary_subseq()` returns `nil` if `begin` is out of range, so it
+// is limited by the length of the array
+pub fn drop(array: Value, count: i64) -> Value {
+    let length = len(array);
+    let begin = if count < length { count } else { length };
+
+    unsafe { rb_ary_subseq(array, begin as c_long, (length - begin) as c_long) }
+}
+
+pub fn index(array: Value, item: Value) -> Value {
+    binding_util::call_method(array, "index", Some(vec![item]))
+}
+
+pub fn tally(array: Value) -> Value {
+    binding_util::call_method(array, "tally", None)
+}
+
+pub fn tally_fallback(array: Value) -> Value {
+    let result = hash::new();
+
+    for offset in 0..len(array) {
+        let item = entry(array, offset);
+        let count = hash::aref(result, item);
+
+        let count = if count.is_nil() {
+            1
+        } else {
+            fixnum::num_to_int(count) + 1
+        };
+
+        hash::aset(result, item, fixnum::int_to_num(count));
+    }
+
+    result
+}
@@ -1,11 +1,52 @@
+use std::collections::{HashMap, HashSet};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Mutex;
+
 use ruby_sys::{class, typed_data};
 
 use binding::util as binding_util;
+use binding::{array, fixnum, hash, string, symbol, vm};
+use result::Error;
 use typed_data::DataTypeWrapper;
-use types::{c_void, Callback, CallbackPtr, Value};
+use types::{c_char, c_int, c_void, AllocCallback, Callback, CallbackPtr, DataType, InternalValue,
+            Value, ValueType};
 use util;
 
-use Object;
+use {Object, Visibility, VM};
+
+extern "C" {
+    // `ruby-sys` does not provide bindings for allocation functions and typed data checks
+    fn rb_define_alloc_func(klass: Value, func: CallbackPtr);
+    fn rb_typeddata_is_kind_of(object: Value, data_type: *const DataType) -> c_int;
+
+    // `ruby-sys` does not provide bindings for defining private and protected methods
+    fn rb_define_private_method(
+        klass: Value,
+        name: *const c_char,
+        func: CallbackPtr,
+        argc: c_int,
+    );
+    fn rb_define_protected_method(
+        klass: Value,
+        name: *const c_char,
+        func: CallbackPtr,
+        argc: c_int,
+    );
+
+    // `ruby-sys` does not provide bindings for class hierarchy checks
+    fn rb_class_inherited_p(klass: Value, other: Value) -> Value;
+}
+
+// Returns an iterator over the items to be yielded by `each` for the receiver
+pub type EachItems = Box<Fn(Value) -> Box<Iterator<Item = Value>>>;
+
+lazy_static! {
+    // Addresses of data types of wrapped structs registered with `register_data_type()`
+    static ref DATA_TYPES: Mutex<HashMap<InternalValue, usize>> = Mutex::new(HashMap::new());
+
+    // Addresses of `EachItems` of classes registered with `define_each_method()`
+    static ref EACH_ITEMS: Mutex<HashMap<InternalValue, usize>> = Mutex::new(HashMap::new());
+}
 
 pub fn define_class(name: &str, superclass: Value) -> Value {
     let name = util::str_to_cstring(name);
@@ -23,6 +64,24 @@ pub fn const_get(klass: Value, name: &str) -> Value {
     unsafe { class::rb_const_get(klass, binding_util::internal_id(name)) }
 }
 
+pub fn class_variable_get(klass: Value, name: &str) -> Value {
+    let name = symbol::id_to_sym(binding_util::internal_id(name));
+
+    binding_util::call_method(klass, "class_variable_get", Some(vec![name]))
+}
+
+pub fn class_variable_set(klass: Value, name: &str, value: Value) -> Value {
+    let name = symbol::id_to_sym(binding_util::internal_id(name));
+
+    binding_util::call_method(klass, "class_variable_set", Some(vec![name, value]))
+}
+
+pub fn const_defined(klass: Value, name: &str) -> bool {
+    let name = symbol::id_to_sym(binding_util::internal_id(name));
+
+    binding_util::call_method(klass, "const_defined?", Some(vec![name])).is_true()
+}
+
 pub fn const_set(klass: Value, name: &str, value: Value) {
     let name = util::str_to_cstring(name);
 
@@ -37,6 +96,16 @@ pub fn superclass(klass: Value) -> Value {
     unsafe { class::rb_class_superclass(klass) }
 }
 
+pub fn name(klass: Value) -> Value {
+    let name = binding_util::call_method(klass, "name", None);
+
+    if name.is_nil() {
+        binding_util::call_method(klass, "inspect", None)
+    } else {
+        name
+    }
+}
+
 pub fn singleton_class(object: Value) -> Value {
     unsafe { class::rb_singleton_class(object) }
 }
@@ -45,6 +114,70 @@ pub fn ancestors(klass: Value) -> Value {
     unsafe { class::rb_mod_ancestors(klass) }
 }
 
+// `predicate` is one of `Module#method_defined?`, `public_method_defined?` and
+// `private_method_defined?`
+pub fn is_method_defined(klass: Value, name: &str, predicate: &str) -> bool {
+    let name = symbol::id_to_sym(binding_util::internal_id(name));
+
+    binding_util::call_method(klass, predicate, Some(vec![name])).is_true()
+}
+
+pub fn includes_module(klass: Value, module: Value) -> bool {
+    binding_util::call_method(klass, "include?", Some(vec![module])).is_true()
+}
+
+// `rb_class_inherited_p()` returns `nil` for unrelated classes and `true` for the class itself,
+// the latter is excluded the same way as in `Module#<`
+pub fn is_subclass_of(klass: Value, other: Value) -> bool {
+    klass != other && unsafe { rb_class_inherited_p(klass, other) }.is_true()
+}
+
+pub fn method(object: Value, name: &str) -> Value {
+    let name = symbol::id_to_sym(binding_util::internal_id(name));
+
+    binding_util::call_method(object, "method", Some(vec![name]))
+}
+
+pub fn instance_method(klass: Value, name: &str) -> Value {
+    let name = symbol::id_to_sym(binding_util::internal_id(name));
+
+    binding_util::call_method(klass, "instance_method", Some(vec![name]))
+}
+
+// Large ids are returned as `Bignum`s, `rb_num2ull()` converts both `Fixnum`s and `Bignum`s
+pub fn object_id(object: Value) -> u64 {
+    let id = binding_util::call_method(object, "object_id", None);
+
+    fixnum::num_to_u64(id)
+}
+
+pub fn methods(object: Value, kind: &str) -> Vec<String> {
+    let methods = binding_util::call_method(object, kind, None);
+
+    symbol::array_to_strings(methods)
+}
+
+pub fn instance_methods(klass: Value, include_inherited: bool) -> Vec<String> {
+    let arguments = Some(vec![util::bool_to_value(include_inherited)]);
+    let methods = binding_util::call_method(klass, "instance_methods", arguments);
+
+    symbol::array_to_strings(methods)
+}
+
+pub fn to_enum(object: Value, method: &str) -> Value {
+    let method = symbol::id_to_sym(binding_util::internal_id(method));
+
+    binding_util::call_method(object, "to_enum", Some(vec![method]))
+}
+
+pub fn is_kind_of(object: Value, klass: Value) -> bool {
+    binding_util::call_method(object, "is_a?", Some(vec![klass])).is_true()
+}
+
+pub fn is_instance_of(object: Value, klass: Value) -> bool {
+    binding_util::call_method(object, "instance_of?", Some(vec![klass])).is_true()
+}
+
 pub fn new_instance(klass: Value, arguments: Option<Vec<Value>>) -> Value {
     let (argc, argv) = util::process_arguments(&arguments);
 
@@ -59,6 +192,101 @@ pub fn instance_variable_set(object: Value, name: &str, value: Value) -> Value {
     unsafe { class::rb_ivar_set(object, binding_util::internal_id(name), value) }
 }
 
+pub fn instance_variables(object: Value) -> Value {
+    binding_util::call_method(object, "instance_variables", None)
+}
+
+pub fn instance_eval(object: Value, code: &str) -> Value {
+    binding_util::call_method(object, "instance_eval", Some(vec![string::new(code)]))
+}
+
+pub fn instance_exec(object: Value, block: Value, arguments: Vec<Value>) -> Value {
+    binding_util::call_method_with_block(object, "instance_exec", Some(arguments), block)
+}
+
+pub fn include_module(klass: Value, module: Value) {
+    binding_util::call_method(klass, "include", Some(vec![module]));
+}
+
+pub fn define_alloc_func<O: Object>(klass: Value, func: AllocCallback<O>) {
+    unsafe { rb_define_alloc_func(klass, func as CallbackPtr) };
+}
+
+pub fn define_typed_alloc_func<T: Default>(klass: Value, wrapper: &'static DataTypeWrapper<T>) {
+    register_data_type(klass, wrapper);
+
+    unsafe { rb_define_alloc_func(klass, typed_alloc::<T> as CallbackPtr) };
+}
+
+// Subclasses inherit the allocation function, their data type is found in superclasses
+extern "C" fn typed_alloc<T: Default>(klass: Value) -> Value {
+    let data_type = match find_data_type(klass) {
+        Some(data_type) => data_type,
+        None => {
+            VM::raise_error(Error::TypeError("Wrapped struct is not defined".to_string()));
+
+            unreachable!()
+        }
+    };
+
+    let data = match panic::catch_unwind(T::default) {
+        Ok(data) => Box::into_raw(Box::new(data)) as *mut c_void,
+        Err(payload) => {
+            VM::raise_error(Error::from_panic(payload));
+
+            // `VM::raise()` does not return, the control is passed back to Ruby
+            unreachable!()
+        }
+    };
+
+    unsafe { typed_data::rb_data_typed_object_wrap(klass, data, data_type) }
+}
+
+// Remembers the data type of structs wrapped into instances of the class for functions which
+// can not receive the wrapper, like method callbacks
+pub fn register_data_type<T>(klass: Value, wrapper: &'static DataTypeWrapper<T>) {
+    let data_type = wrapper.data_type() as *const DataType as usize;
+
+    DATA_TYPES.lock().unwrap().insert(klass.value, data_type);
+}
+
+// Subclasses inherit the data type, so it is looked up in superclasses as well
+pub fn find_data_type(klass: Value) -> Option<&'static DataType> {
+    find_in_ancestors(&DATA_TYPES, klass).map(|data_type| unsafe {
+        &*(data_type as *const DataType)
+    })
+}
+
+fn find_in_ancestors(
+    registry: &Mutex<HashMap<InternalValue, usize>>,
+    klass: Value,
+) -> Option<usize> {
+    let registry = registry.lock().unwrap();
+    let mut ancestor = klass;
+
+    while !ancestor.is_nil() {
+        if let Some(&address) = registry.get(&ancestor.value) {
+            return Some(address);
+        }
+
+        ancestor = superclass(ancestor);
+    }
+
+    None
+}
+
+pub fn is_typed_data_of(object: Value, data_type: &DataType) -> bool {
+    util::c_int_to_bool(unsafe { rb_typeddata_is_kind_of(object, data_type) })
+}
+
+pub fn get_typed_data<T>(object: Value, data_type: &DataType) -> &mut T {
+    unsafe {
+        let data = typed_data::rb_check_typeddata(object, data_type);
+
+        &mut *(data as *mut T)
+    }
+}
+
 pub fn define_attribute(object: Value, name: &str, reader: bool, writer: bool) {
     let name = util::str_to_cstring(name);
     let reader = util::bool_to_c_int(reader);
@@ -73,6 +301,14 @@ pub fn respond_to(object: Value, method: &str) -> bool {
     util::c_int_to_bool(result)
 }
 
+pub fn respond_to_including_private(object: Value, method: &str) -> bool {
+    let method = symbol::id_to_sym(binding_util::internal_id(method));
+    let include_all = util::bool_to_value(true);
+    let result = binding_util::call_method(object, "respond_to?", Some(vec![method, include_all]));
+
+    result.is_true()
+}
+
 pub fn define_method<I: Object, O: Object>(klass: Value, name: &str, callback: Callback<I, O>) {
     let name = util::str_to_cstring(name);
 
@@ -81,6 +317,61 @@ pub fn define_method<I: Object, O: Object>(klass: Value, name: &str, callback: C
     }
 }
 
+pub fn alias_method(klass: Value, new_name: &str, original: &str) -> Value {
+    let new_name = symbol::id_to_sym(binding_util::internal_id(new_name));
+    let original = symbol::id_to_sym(binding_util::internal_id(original));
+
+    binding_util::call_method(klass, "alias_method", Some(vec![new_name, original]))
+}
+
+pub fn remove_method(klass: Value, name: &str) -> Value {
+    let name = symbol::id_to_sym(binding_util::internal_id(name));
+
+    binding_util::call_method(klass, "remove_method", Some(vec![name]))
+}
+
+pub fn undef_method(klass: Value, name: &str) -> Value {
+    let name = symbol::id_to_sym(binding_util::internal_id(name));
+
+    binding_util::call_method(klass, "undef_method", Some(vec![name]))
+}
+
+pub fn define_private_method<I: Object, O: Object>(
+    klass: Value,
+    name: &str,
+    callback: Callback<I, O>,
+) {
+    let name = util::str_to_cstring(name);
+
+    unsafe {
+        rb_define_private_method(klass, name.as_ptr(), callback as CallbackPtr, -1);
+    }
+}
+
+pub fn define_protected_method<I: Object, O: Object>(
+    klass: Value,
+    name: &str,
+    callback: Callback<I, O>,
+) {
+    let name = util::str_to_cstring(name);
+
+    unsafe {
+        rb_define_protected_method(klass, name.as_ptr(), callback as CallbackPtr, -1);
+    }
+}
+
+pub fn set_method_visibility(klass: Value, name: &str, visibility: Visibility) {
+    let name = symbol::id_to_sym(binding_util::internal_id(name));
+
+    let method = match visibility {
+        Visibility::Public => "public",
+        Visibility::Protected => "protected",
+        Visibility::Private => "private",
+    };
+
+    binding_util::call_method(klass, method, Some(vec![name]));
+}
+
 pub fn define_singleton_method<I: Object, O: Object>(
     klass: Value,
     name: &str,
@@ -93,6 +384,84 @@ pub fn define_singleton_method<I: Object, O: Object>(
     }
 }
 
+pub fn define_singleton_proc(object: Value, name: &str, rproc: Value) {
+    let name = symbol::id_to_sym(binding_util::internal_id(name));
+
+    binding_util::call_method(object, "define_singleton_method", Some(vec![name, rproc]));
+}
+
+// Defines `each` which yields the items returned by `items` for the receiver one by one.
+// `EachItems` are never freed, because the method can be called while the class exists.
+pub fn define_each_method(klass: Value, items: EachItems) {
+    let items = Box::into_raw(Box::new(items)) as usize;
+    let name = util::str_to_cstring("each");
+
+    EACH_ITEMS.lock().unwrap().insert(klass.value, items);
+
+    unsafe {
+        class::rb_define_method(klass, name.as_ptr(), each as CallbackPtr, 0);
+    }
+}
+
+// Without a block `each` returns an enumerator like the core classes do.
+//
+// The block is called with `rb_protect()`, so `break` and exceptions do not unwind Rust frames.
+// They are resumed after the iterator is dropped.
+extern "C" fn each(itself: Value) -> Value {
+    if !vm::is_block_given() {
+        let method = symbol::id_to_sym(binding_util::internal_id("each"));
+
+        return binding_util::call_method(itself, "enum_for", Some(vec![method]));
+    }
+
+    let items = match find_in_ancestors(&EACH_ITEMS, object_class(itself)) {
+        Some(items) => unsafe { &*(items as *const EachItems) },
+        None => {
+            VM::raise_error(Error::TypeError("`each` is not defined".to_string()));
+
+            unreachable!()
+        }
+    };
+
+    let mut iterator = match panic::catch_unwind(AssertUnwindSafe(|| items(itself))) {
+        Ok(iterator) => iterator,
+        Err(payload) => {
+            VM::raise_error(Error::from_panic(payload));
+
+            // `VM::raise()` does not return, the control is passed back to Ruby
+            unreachable!()
+        }
+    };
+
+    loop {
+        let item = match panic::catch_unwind(AssertUnwindSafe(|| iterator.next())) {
+            Ok(Some(item)) => item,
+            Ok(None) => break,
+            Err(payload) => {
+                drop(iterator);
+                VM::raise_error(Error::from_panic(payload));
+
+                // `VM::raise()` does not return, the control is passed back to Ruby
+                unreachable!()
+            }
+        };
+
+        let yielded = vm::protect(|| {
+            vm::yield_object(item);
+        });
+
+        if let Err(state) = yielded {
+            drop(iterator);
+            vm::jump_tag(state);
+
+            // `rb_jump_tag()` does not return
+            unreachable!()
+        }
+    }
+
+    itself
+}
+
 pub fn wrap_data<T>(klass: Value, data: T, wrapper: &DataTypeWrapper<T>) -> Value {
     let data = Box::into_raw(Box::new(data)) as *mut c_void;
 
@@ -100,11 +469,12 @@ pub fn wrap_data<T>(klass: Value, data: T, wrapper: &DataTypeWrapper<T>) -> Valu
 }
 
 pub fn get_data<T>(object: Value, wrapper: &DataTypeWrapper<T>) -> &mut T {
-    unsafe {
-        let data = typed_data::rb_check_typeddata(object, wrapper.data_type());
+    get_typed_data(object, wrapper.data_type())
+}
 
-        &mut *(data as *mut T)
-    }
+// The previously wrapped struct is dropped
+pub fn set_data<T>(object: Value, data: T, wrapper: &DataTypeWrapper<T>) {
+    *get_data(object, wrapper) = data;
 }
 
 pub fn is_frozen(object: Value) -> Value {
@@ -114,3 +484,49 @@ pub fn is_frozen(object: Value) -> Value {
 pub fn freeze(object: Value) -> Value {
     unsafe { class::rb_obj_freeze(object) }
 }
+
+pub fn deep_freeze(object: Value) -> Value {
+    let mut seen = HashSet::new();
+
+    deep_freeze_value(object, &mut seen);
+
+    object
+}
+
+// `seen` contains already visited objects, so cyclic structures are frozen only once
+fn deep_freeze_value(object: Value, seen: &mut HashSet<InternalValue>) {
+    if !seen.insert(object.value) {
+        return;
+    }
+
+    let mut children = Vec::new();
+
+    match object.ty() {
+        ValueType::Array => {
+            for index in 0..array::len(object) {
+                children.push(array::entry(object, index));
+            }
+        }
+        ValueType::Hash => {
+            hash::each(object, |key, value| {
+                children.push(key.value());
+                children.push(value.value());
+            });
+        }
+        _ => {}
+    }
+
+    for child in children {
+        deep_freeze_value(child, seen);
+    }
+
+    freeze(object);
+}
+
+pub fn dup(object: Value) -> Value {
+    binding_util::call_method(object, "dup", None)
+}
+
+pub fn clone(object: Value) -> Value {
+    binding_util::call_method(object, "clone", None)
+}
@@ -0,0 +1,104 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+
+use binding::global::rb_cObject;
+use binding::util as binding_util;
+use binding::{class, gc, symbol};
+use result::Error;
+use typed_data::{self, DataTypeWrapper};
+use types::{c_void, Argc, DataType, DataTypeFunction, Value};
+use util;
+
+use {AnyObject, Object, VM};
+
+pub type BoxedClosure = Box<FnMut(&[AnyObject]) -> AnyObject>;
+
+pub struct ClosureWrapper {
+    data_type: DataType,
+}
+
+impl ClosureWrapper {
+    fn new() -> Self {
+        let name = util::str_to_cstring("Ruru/Closure");
+        let reserved_bytes: [*mut c_void; 2] = [ptr::null_mut(); 2];
+
+        let data_type = DataType {
+            wrap_struct_name: name.into_raw(),
+            parent: ptr::null(),
+            data: ptr::null_mut(),
+            flags: Value::from(0),
+
+            function: DataTypeFunction {
+                dmark: None,
+                dfree: Some(typed_data::free::<BoxedClosure>),
+                dsize: None,
+                reserved: reserved_bytes,
+            },
+        };
+
+        ClosureWrapper { data_type: data_type }
+    }
+}
+
+unsafe impl Sync for ClosureWrapper {}
+
+impl DataTypeWrapper<BoxedClosure> for ClosureWrapper {
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+}
+
+// Closures are wrapped into instances of an anonymous class, so no constant is defined in the
+// user's namespace
+struct ClosureClass(Value);
+
+unsafe impl Sync for ClosureClass {}
+
+lazy_static! {
+    static ref CLOSURE_WRAPPER: ClosureWrapper = ClosureWrapper::new();
+
+    static ref CLOSURE_CLASS: ClosureClass = {
+        let object_class = unsafe { rb_cObject };
+        let class_class = binding_util::get_constant("Class", object_class);
+        let klass = binding_util::call_method(class_class, "new", Some(vec![object_class]));
+
+        class::define_method(klass, "call", call_closure);
+
+        // The class is not referenced by any constant
+        gc::register_mark_object(klass);
+
+        ClosureClass(klass)
+    };
+}
+
+// Wraps the closure into an object which calls the closure in `#call`
+pub fn wrap(closure: BoxedClosure) -> Value {
+    class::wrap_data(CLOSURE_CLASS.0, closure, &*CLOSURE_WRAPPER)
+}
+
+pub fn to_proc(closure: Value) -> Value {
+    let call = symbol::id_to_sym(binding_util::internal_id("call"));
+    let method = binding_util::call_method(closure, "method", Some(vec![call]));
+
+    binding_util::call_method(method, "to_proc", None)
+}
+
+// A panic can not unwind into Ruby, so it is converted to `RuntimeError` like in `methods!`
+extern "C" fn call_closure(argc: Argc, argv: *const AnyObject, itself: AnyObject) -> AnyObject {
+    let result = {
+        let arguments = VM::parse_arguments(argc, argv);
+        let closure = class::get_data(itself.value(), &*CLOSURE_WRAPPER);
+
+        panic::catch_unwind(AssertUnwindSafe(|| closure(&arguments)))
+    };
+
+    match result {
+        Ok(object) => object,
+        Err(payload) => {
+            VM::raise_error(Error::from_panic(payload));
+
+            // `VM::raise()` does not return, the control is passed back to Ruby
+            unreachable!()
+        }
+    }
+}
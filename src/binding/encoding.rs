@@ -0,0 +1,26 @@
+use binding::global::rb_cObject;
+use binding::util as binding_util;
+use binding::{string, vm};
+use types::Value;
+
+pub fn utf8() -> Value {
+    binding_util::get_constant("UTF_8", encoding_class())
+}
+
+pub fn ascii_8bit() -> Value {
+    binding_util::get_constant("ASCII_8BIT", encoding_class())
+}
+
+pub fn find(name: &str) -> Result<Value, Value> {
+    let name = string::new(name);
+
+    vm::protect_value(|| binding_util::call_method(encoding_class(), "find", Some(vec![name])))
+}
+
+pub fn name(encoding: Value) -> Value {
+    binding_util::call_method(encoding, "name", None)
+}
+
+pub fn encoding_class() -> Value {
+    binding_util::get_constant("Encoding", unsafe { rb_cObject })
+}
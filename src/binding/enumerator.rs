@@ -0,0 +1,7 @@
+use binding::util as binding_util;
+use binding::vm;
+use types::Value;
+
+pub fn next(enumerator: Value) -> Result<Value, Value> {
+    vm::protect_value(|| binding_util::call_method(enumerator, "next", None))
+}
@@ -0,0 +1,56 @@
+use std::slice;
+
+use binding::rproc;
+use types::{c_int, CallbackPtr, Value};
+use util;
+
+extern "C" {
+    // `ruby-sys` does not provide bindings for `Fiber`
+    fn rb_fiber_new(func: CallbackPtr, obj: Value) -> Value;
+    fn rb_fiber_resume(fiber: Value, argc: c_int, argv: *const Value) -> Value;
+    fn rb_fiber_yield(argc: c_int, argv: *const Value) -> Value;
+    fn rb_fiber_current() -> Value;
+    fn rb_fiber_alive_p(fiber: Value) -> Value;
+}
+
+// Called by Ruby on the first `resume()` of the fiber with arguments of `resume()`
+extern "C" fn call_proc(
+    _yielded_argument: Value,
+    rproc: Value,
+    argc: c_int,
+    argv: *const Value,
+    _block: Value,
+) -> Value {
+    let arguments = if argc > 0 {
+        unsafe { slice::from_raw_parts(argv, argc as usize).to_vec() }
+    } else {
+        Vec::new()
+    };
+
+    rproc::call(rproc, Some(arguments))
+}
+
+// The proc is stored as the callback argument of the fiber and is marked by Ruby
+pub fn new(rproc: Value) -> Value {
+    unsafe { rb_fiber_new(call_proc as CallbackPtr, rproc) }
+}
+
+pub fn resume(fiber: Value, arguments: Option<Vec<Value>>) -> Value {
+    let (argc, argv) = util::process_arguments(&arguments);
+
+    unsafe { rb_fiber_resume(fiber, argc, argv) }
+}
+
+pub fn yield_value(arguments: Option<Vec<Value>>) -> Value {
+    let (argc, argv) = util::process_arguments(&arguments);
+
+    unsafe { rb_fiber_yield(argc, argv) }
+}
+
+pub fn current() -> Value {
+    unsafe { rb_fiber_current() }
+}
+
+pub fn is_alive(fiber: Value) -> bool {
+    unsafe { rb_fiber_alive_p(fiber).is_true() }
+}
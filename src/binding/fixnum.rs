@@ -1,11 +1,26 @@
 use ruby_sys::fixnum;
 
+use binding::util as binding_util;
 use types::{SignedValue, Value};
 
+extern "C" {
+    // `rb_num2int()` from `ruby-sys` is limited to `i32` range on 64-bit platforms
+    fn rb_num2ll(num: Value) -> i64;
+    fn rb_num2ull(num: Value) -> u64;
+}
+
 pub fn int_to_num(num: i64) -> Value {
     unsafe { fixnum::rb_int2inum(num as SignedValue) }
 }
 
 pub fn num_to_int(num: Value) -> i64 {
-    unsafe { fixnum::rb_num2int(num) as i64 }
+    unsafe { rb_num2ll(num) }
+}
+
+pub fn num_to_u64(num: Value) -> u64 {
+    unsafe { rb_num2ull(num) }
+}
+
+pub fn to_s(num: Value, radix: u32) -> Value {
+    binding_util::call_method(num, "to_s", Some(vec![int_to_num(radix as i64)]))
 }
@@ -2,6 +2,45 @@ use ruby_sys::gc;
 
 use types::Value;
 
+extern "C" {
+    // `ruby-sys` does not provide bindings for registering global addresses and objects
+    fn rb_gc_register_address(address: *mut Value);
+    fn rb_gc_unregister_address(address: *mut Value);
+    fn rb_gc_register_mark_object(object: Value);
+
+    // `ruby-sys` does not provide bindings for starting, disabling and enabling the garbage
+    // collector
+    fn rb_gc_start() -> Value;
+    fn rb_gc_disable() -> Value;
+    fn rb_gc_enable() -> Value;
+}
+
 pub fn mark(value: Value) {
     unsafe { gc::rb_gc_mark(value) };
 }
+
+pub fn register_address(address: *mut Value) {
+    unsafe { rb_gc_register_address(address) };
+}
+
+pub fn unregister_address(address: *mut Value) {
+    unsafe { rb_gc_unregister_address(address) };
+}
+
+pub fn register_mark_object(object: Value) {
+    unsafe { rb_gc_register_mark_object(object) };
+}
+
+pub fn start() {
+    unsafe { rb_gc_start() };
+}
+
+// Returns `true` if the garbage collector was already disabled
+pub fn disable() -> bool {
+    unsafe { rb_gc_disable() }.is_true()
+}
+
+// Returns `true` if the garbage collector was disabled
+pub fn enable() -> bool {
+    unsafe { rb_gc_enable() }.is_true()
+}
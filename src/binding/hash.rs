@@ -1,13 +1,18 @@
 use ruby_sys::hash;
 
 use binding::fixnum;
+use binding::util as binding_util;
 use types::{CallbackMutPtr, CallbackPtr, Value};
-use AnyObject;
+use {AnyObject, Object};
 
 pub fn new() -> Value {
     unsafe { hash::rb_hash_new() }
 }
 
+pub fn set_default(hash: Value, default: Value) -> Value {
+    binding_util::call_method(hash, "default=", Some(vec![default]))
+}
+
 pub fn aref(hash: Value, key: Value) -> Value {
     unsafe { hash::rb_hash_aref(hash, key) }
 }
@@ -28,6 +33,33 @@ pub fn dup(hash: Value) -> Value {
     unsafe { hash::rb_hash_dup(hash) }
 }
 
+pub fn has_key(hash: Value, key: Value) -> bool {
+    binding_util::call_method(hash, "key?", Some(vec![key])).is_true()
+}
+
+pub fn update(hash: Value, other: Value) -> Value {
+    binding_util::call_method(hash, "update", Some(vec![other]))
+}
+
+pub fn update_with<F>(hash: Value, other: Value, mut merge_callback: F)
+where
+    F: FnMut(AnyObject, AnyObject, AnyObject) -> AnyObject,
+{
+    each(other, |key, new_value| {
+        let key = key.value();
+
+        let value = if has_key(hash, key) {
+            let old_value = AnyObject::from(aref(hash, key));
+
+            merge_callback(AnyObject::from(key), old_value, new_value).value()
+        } else {
+            new_value.value()
+        };
+
+        aset(hash, key, value);
+    });
+}
+
 pub fn length(hash: Value) -> i64 {
     unsafe {
         let size = hash::rb_hash_size(hash);
@@ -0,0 +1,29 @@
+use binding::fixnum;
+use binding::util as binding_util;
+use types::Value;
+
+pub fn call(method: Value, arguments: Option<Vec<Value>>) -> Value {
+    binding_util::call_method(method, "call", arguments)
+}
+
+pub fn arity(method: Value) -> i64 {
+    let arity = binding_util::call_method(method, "arity", None);
+
+    fixnum::num_to_int(arity)
+}
+
+pub fn to_proc(method: Value) -> Value {
+    binding_util::call_method(method, "to_proc", None)
+}
+
+pub fn unbind(method: Value) -> Value {
+    binding_util::call_method(method, "unbind", None)
+}
+
+pub fn bind(unbound_method: Value, object: Value) -> Value {
+    binding_util::call_method(unbound_method, "bind", Some(vec![object]))
+}
+
+pub fn name(method: Value) -> Value {
+    binding_util::call_method(method, "name", None)
+}
@@ -1,13 +1,23 @@
 pub mod array;
 pub mod class;
+pub mod closure;
+pub mod encoding;
+pub mod enumerator;
+pub mod fiber;
 pub mod fixnum;
 pub mod float;
 pub mod gc;
 pub mod global;
 pub mod hash;
+pub mod method;
+pub mod module;
+pub mod mutex;
+pub mod object_space;
+pub mod queue;
 pub mod rproc;
 pub mod string;
 pub mod symbol;
 pub mod thread;
 pub mod util;
 pub mod vm;
+pub mod weak_ref;
@@ -0,0 +1,19 @@
+use binding::util as binding_util;
+use types::{c_char, Value};
+use util;
+
+extern "C" {
+    // `ruby-sys` does not provide bindings for defining modules
+    fn rb_define_module(name: *const c_char) -> Value;
+}
+
+// An existing module is reopened, `TypeError` is raised if the constant is not a module
+pub fn define_module(name: &str) -> Value {
+    let name = util::str_to_cstring(name);
+
+    unsafe { rb_define_module(name.as_ptr()) }
+}
+
+pub fn extend_object(object: Value, module: Value) {
+    binding_util::call_method(object, "extend", Some(vec![module]));
+}
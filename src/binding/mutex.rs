@@ -0,0 +1,82 @@
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+
+use binding::global::RubySpecialConsts;
+use types::{c_void, CallbackPtr, InternalValue, Value};
+
+extern "C" {
+    // `ruby-sys` does not provide bindings for `Mutex`
+    fn rb_mutex_new() -> Value;
+    fn rb_mutex_lock(mutex: Value) -> Value;
+    fn rb_mutex_unlock(mutex: Value) -> Value;
+    fn rb_mutex_locked_p(mutex: Value) -> Value;
+    fn rb_mutex_synchronize(mutex: Value, func: CallbackPtr, arg: *mut c_void) -> Value;
+}
+
+struct Synchronized<F> {
+    func: Option<F>,
+    panic: Option<Box<Any + Send>>,
+}
+
+pub fn new() -> Value {
+    unsafe { rb_mutex_new() }
+}
+
+pub fn lock(mutex: Value) {
+    unsafe { rb_mutex_lock(mutex) };
+}
+
+pub fn unlock(mutex: Value) {
+    unsafe { rb_mutex_unlock(mutex) };
+}
+
+pub fn is_locked(mutex: Value) -> bool {
+    unsafe { rb_mutex_locked_p(mutex) }.is_true()
+}
+
+// `rb_mutex_synchronize()` unlocks the mutex even if the closure raises an exception and
+// re-raises it afterwards. A panic is caught in the callback and resumed after the mutex is
+// unlocked.
+pub fn synchronize<F>(mutex: Value, func: F) -> Value
+where
+    F: FnOnce() -> Value,
+{
+    let mut data = Synchronized {
+        func: Some(func),
+        panic: None,
+    };
+
+    let result = unsafe {
+        let data = &mut data as *mut Synchronized<F> as *mut c_void;
+
+        rb_mutex_synchronize(mutex, call_synchronized::<F> as CallbackPtr, data)
+    };
+
+    if let Some(payload) = data.panic {
+        panic::resume_unwind(payload);
+    }
+
+    result
+}
+
+extern "C" fn call_synchronized<F>(data: *mut c_void) -> Value
+where
+    F: FnOnce() -> Value,
+{
+    let data = unsafe { &mut *(data as *mut Synchronized<F>) };
+    let nil = Value::from(RubySpecialConsts::Nil as InternalValue);
+
+    let func = match data.func.take() {
+        Some(func) => func,
+        None => return nil,
+    };
+
+    match panic::catch_unwind(AssertUnwindSafe(func)) {
+        Ok(value) => value,
+        Err(payload) => {
+            data.panic = Some(payload);
+
+            nil
+        }
+    }
+}
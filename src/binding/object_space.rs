@@ -0,0 +1,80 @@
+use binding::{array, gc};
+use types::{c_int, c_void, size_t, InternalValue, Value};
+
+extern "C" {
+    // `ruby-sys` does not provide bindings for heap iteration
+    fn rb_objspace_each_objects(
+        callback: extern "C" fn(*mut c_void, *mut c_void, size_t, *mut c_void) -> c_int,
+        data: *mut c_void,
+    );
+    fn rb_objspace_internal_object_p(object: Value) -> c_int;
+    fn rb_obj_is_kind_of(object: Value, klass: Value) -> Value;
+}
+
+struct Collected {
+    klass: Option<Value>,
+    objects: Vec<Value>,
+}
+
+// Called for each page of the heap. Ruby objects must not be allocated here, so the objects
+// are collected into a `Vec`
+extern "C" fn collect_objects(
+    start: *mut c_void,
+    end: *mut c_void,
+    stride: size_t,
+    data: *mut c_void,
+) -> c_int {
+    let collected = unsafe { &mut *(data as *mut Collected) };
+    let mut address = start as usize;
+
+    while address < end as usize {
+        let object = Value::from(address as InternalValue);
+
+        // Free slots and internal objects (nodes, hidden objects, etc.) are skipped
+        if unsafe { rb_objspace_internal_object_p(object) } == 0 {
+            let matches = match collected.klass {
+                Some(klass) => unsafe { rb_obj_is_kind_of(object, klass) }.is_true(),
+                None => true,
+            };
+
+            if matches {
+                collected.objects.push(object);
+            }
+        }
+
+        address += stride as usize;
+    }
+
+    0
+}
+
+// Collects objects into an array, so they are not garbage collected while being iterated.
+// Garbage collection is disabled until the array is built, because the collected `Vec` is not
+// visible to the garbage collector
+pub fn each_object(klass: Option<Value>) -> Value {
+    let mut collected = Collected {
+        klass: klass,
+        objects: Vec::new(),
+    };
+
+    let was_disabled = gc::disable();
+
+    unsafe {
+        rb_objspace_each_objects(
+            collect_objects,
+            &mut collected as *mut Collected as *mut c_void,
+        )
+    };
+
+    let objects = array::new();
+
+    for object in collected.objects {
+        array::push(objects, object);
+    }
+
+    if !was_disabled {
+        gc::enable();
+    }
+
+    objects
+}
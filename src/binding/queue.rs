@@ -0,0 +1,31 @@
+use binding::global::rb_cObject;
+use binding::util as binding_util;
+use binding::fixnum;
+use types::Value;
+use util;
+
+pub fn new() -> Value {
+    let queue_class = binding_util::get_constant("Queue", unsafe { rb_cObject });
+
+    binding_util::call_method(queue_class, "new", None)
+}
+
+pub fn push(queue: Value, item: Value) -> Value {
+    binding_util::call_method(queue, "push", Some(vec![item]))
+}
+
+pub fn pop(queue: Value, non_block: bool) -> Value {
+    let non_block = util::bool_to_value(non_block);
+
+    binding_util::call_method(queue, "pop", Some(vec![non_block]))
+}
+
+pub fn size(queue: Value) -> usize {
+    let size = binding_util::call_method(queue, "size", None);
+
+    fixnum::num_to_u64(size) as usize
+}
+
+pub fn is_empty(queue: Value) -> bool {
+    binding_util::call_method(queue, "empty?", None).is_true()
+}
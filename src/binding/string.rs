@@ -1,8 +1,17 @@
 use ruby_sys::string;
 
-use types::{c_char, c_long, Value};
+use binding::util as binding_util;
+use binding::vm;
+use types::{c_char, c_int, c_long, Value};
 use util;
 
+extern "C" {
+    // `ruby-sys` does not provide bindings for string comparison
+    fn rb_str_cmp(string: Value, other: Value) -> c_int;
+    // `ruby-sys` does not provide bindings for deduplicated strings
+    fn rb_fstring(string: Value) -> Value;
+}
+
 pub fn new(string: &str) -> Value {
     let str = string.as_ptr() as *const c_char;
     let len = string.len() as c_long;
@@ -17,6 +26,40 @@ pub fn new_utf8(string: &str) -> Value {
     unsafe { string::rb_utf8_str_new(str, len) }
 }
 
+// `rb_fstring()` returns a frozen string from the table of deduplicated strings
+pub fn new_frozen(string: &str) -> Value {
+    unsafe { rb_fstring(new_utf8(string)) }
+}
+
+pub fn new_from_bytes(bytes: &[u8]) -> Value {
+    let str = bytes.as_ptr() as *const c_char;
+    let len = bytes.len() as c_long;
+
+    unsafe { string::rb_str_new(str, len) }
+}
+
+pub fn format(template: &str, mut arguments: Vec<Value>) -> Value {
+    arguments.insert(0, new(template));
+
+    vm::call_kernel_method("sprintf", Some(arguments))
+}
+
+pub fn compare(string: Value, other: Value) -> i64 {
+    unsafe { rb_str_cmp(string, other) as i64 }
+}
+
+pub fn encoding(string: Value) -> Value {
+    binding_util::call_method(string, "encoding", None)
+}
+
+pub fn force_encoding(string: Value, encoding: Value) -> Value {
+    binding_util::call_method(string, "force_encoding", Some(vec![encoding]))
+}
+
+pub fn encode(string: Value, encoding: Value) -> Result<Value, Value> {
+    vm::protect_value(|| binding_util::call_method(string, "encode", Some(vec![encoding])))
+}
+
 pub fn value_to_string(value: Value) -> String {
     unsafe {
         let str = string::rb_string_value_cstr(&value);
@@ -55,6 +98,15 @@ pub fn value_to_str_unchecked<'a>(value: Value) -> &'a str {
     }
 }
 
+pub fn value_to_bytes_unchecked<'a>(value: Value) -> &'a [u8] {
+    unsafe {
+        let str = string::rb_string_value_ptr(&value) as *const u8;
+        let len = string::rb_str_len(value) as usize;
+
+        ::std::slice::from_raw_parts(str, len)
+    }
+}
+
 pub fn bytesize(value: Value) -> i64 {
     unsafe { string::rb_str_len(value) as i64 }
 }
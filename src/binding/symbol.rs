@@ -1,5 +1,6 @@
 use ruby_sys::symbol;
 
+use binding::array;
 use types::{c_char, Id, Value};
 use util;
 
@@ -15,6 +16,12 @@ pub fn value_to_string(value: Value) -> String {
     unsafe { util::cstr_to_string(ptr) }
 }
 
+pub fn array_to_strings(symbols: Value) -> Vec<String> {
+    (0..array::len(symbols))
+        .map(|offset| value_to_string(array::entry(symbols, offset)))
+        .collect()
+}
+
 pub fn id_to_sym(id: Id) -> Value {
     unsafe { symbol::rb_id2sym(id) }
 }
@@ -1,8 +1,20 @@
 use ruby_sys::util as ruby_sys_util;
 
-use types::{Id, Value};
+use binding::symbol;
+use types::{c_int, Id, Value};
 use util;
 
+extern "C" {
+    // `ruby-sys` does not provide bindings for calling methods with a block
+    fn rb_funcall_with_block(
+        receiver: Value,
+        method: Id,
+        argc: c_int,
+        argv: *const Value,
+        block: Value,
+    ) -> Value;
+}
+
 pub fn get_constant(name: &str, parent_object: Value) -> Value {
     let constant_id = internal_id(name);
 
@@ -22,3 +34,24 @@ pub fn call_method(receiver: Value, method: &str, arguments: Option<Vec<Value>>)
     // TODO: Update the signature of `rb_funcallv` in ruby-sys to receive an `Option`
     unsafe { ruby_sys_util::rb_funcallv(receiver, method_id, argc, argv) }
 }
+
+pub fn call_method_with_block(
+    receiver: Value,
+    method: &str,
+    arguments: Option<Vec<Value>>,
+    block: Value,
+) -> Value {
+    let (argc, argv) = util::process_arguments(&arguments);
+    let method_id = internal_id(method);
+
+    unsafe { rb_funcall_with_block(receiver, method_id, argc, argv, block) }
+}
+
+pub fn call_public_method(receiver: Value, method: &str, arguments: Option<Vec<Value>>) -> Value {
+    let method = symbol::id_to_sym(internal_id(method));
+
+    let mut public_send_arguments = vec![method];
+    public_send_arguments.extend(arguments.unwrap_or_default());
+
+    call_method(receiver, "public_send", Some(public_send_arguments))
+}
@@ -2,9 +2,24 @@ use std::ptr;
 
 use ruby_sys::{thread, vm};
 
-use types::{c_int, c_void, CallbackPtr, Value};
+use binding::global::{rb_cObject, RubySpecialConsts};
+use binding::{class, string, symbol};
+use binding::util as binding_util;
+use types::{c_int, c_void, Callback, CallbackPtr, InternalValue, Value};
 use util;
 
+use Object;
+
+extern "C" {
+    // `ruby-sys` does not provide bindings for raising exception objects
+    fn rb_exc_new_str(klass: Value, message: Value) -> Value;
+    fn rb_exc_raise(exception: Value);
+
+    // `ruby-sys` does not provide bindings for yielding and resuming jumps caught by `rb_protect()`
+    fn rb_yield(value: Value) -> Value;
+    fn rb_jump_tag(state: c_int);
+}
+
 pub fn block_proc() -> Value {
     unsafe { vm::rb_block_proc() }
 }
@@ -21,6 +36,55 @@ pub fn init() {
     }
 }
 
+lazy_static! {
+    static ref RUBY_VERSION_STRING: String = {
+        let version = binding_util::get_constant("RUBY_VERSION", unsafe { rb_cObject });
+
+        string::value_to_string(version)
+    };
+
+    static ref RUBY_VERSION: (u32, u32, u32) = parse_ruby_version(&RUBY_VERSION_STRING);
+}
+
+// `RUBY_VERSION` can not change while the VM is running, so it is parsed only once
+pub fn ruby_version() -> (u32, u32, u32) {
+    *RUBY_VERSION
+}
+
+pub fn ruby_version_string() -> &'static str {
+    &RUBY_VERSION_STRING
+}
+
+pub fn ruby_version_at_least(major: u32, minor: u32, teeny: u32) -> bool {
+    ruby_version() >= (major, minor, teeny)
+}
+
+fn parse_ruby_version(version: &str) -> (u32, u32, u32) {
+    let mut parts = version.split('.').map(|part| part.parse().unwrap_or(0));
+
+    let major = parts.next().unwrap_or(0);
+    let minor = parts.next().unwrap_or(0);
+    let teeny = parts.next().unwrap_or(0);
+
+    (major, minor, teeny)
+}
+
+pub fn define_global_function<I: Object, O: Object>(name: &str, callback: Callback<I, O>) {
+    let kernel = binding_util::get_constant("Kernel", unsafe { rb_cObject });
+
+    class::define_method(kernel, name, callback);
+
+    let name = symbol::id_to_sym(binding_util::internal_id(name));
+
+    binding_util::call_method(kernel, "module_function", Some(vec![name]));
+}
+
+pub fn call_kernel_method(method: &str, arguments: Option<Vec<Value>>) -> Value {
+    let kernel = binding_util::get_constant("Kernel", unsafe { rb_cObject });
+
+    binding_util::call_method(kernel, method, arguments)
+}
+
 pub fn require(name: &str) {
     let name = util::str_to_cstring(name);
 
@@ -29,12 +93,33 @@ pub fn require(name: &str) {
     }
 }
 
+pub fn errinfo() -> Value {
+    unsafe { vm::rb_errinfo() }
+}
+
+pub fn set_errinfo(error: Value) {
+    unsafe { vm::rb_set_errinfo(error) };
+}
+
+// The message is not passed to `rb_raise()`, because it would be used as a format string
 pub fn raise(exception: Value, message: &str) {
-    let message = util::str_to_cstring(message);
+    let exception = unsafe { rb_exc_new_str(exception, string::new(message)) };
 
-    unsafe {
-        vm::rb_raise(exception, message.as_ptr());
-    }
+    raise_exception(exception);
+}
+
+// Raises the exception object itself, so its backtrace and instance variables are preserved
+pub fn raise_exception(exception: Value) {
+    unsafe { rb_exc_raise(exception) };
+}
+
+pub fn yield_object(value: Value) -> Value {
+    unsafe { rb_yield(value) }
+}
+
+// Resumes an exception, `break` or `throw` caught by `protect()`
+pub fn jump_tag(state: c_int) {
+    unsafe { rb_jump_tag(state) };
 }
 
 pub fn thread_call_without_gvl<F, R, G>(func: F, unblock_func: Option<G>) -> R
@@ -126,3 +211,23 @@ where
         Err(state)
     }
 }
+
+pub fn protect_value<F>(func: F) -> Result<Value, Value>
+where
+    F: FnOnce() -> Value,
+{
+    let nil = Value::from(RubySpecialConsts::Nil as InternalValue);
+    let mut result = nil;
+
+    match protect(|| result = func()) {
+        Ok(_) => Ok(result),
+        Err(_) => {
+            let exception = errinfo();
+
+            // The exception is handled by the caller, do not leave it pending for Ruby
+            set_errinfo(nil);
+
+            Err(exception)
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use binding::global::rb_cObject;
+use binding::util as binding_util;
+use binding::vm;
+use types::{c_int, Id, Value};
+
+extern "C" {
+    // `ruby-sys` does not provide bindings for constant and class checks
+    fn rb_const_defined(klass: Value, id: Id) -> c_int;
+    fn rb_obj_is_kind_of(object: Value, klass: Value) -> Value;
+}
+
+pub fn new(object: Value) -> Value {
+    vm::require("weakref");
+
+    let weak_ref_class = binding_util::get_constant("WeakRef", unsafe { rb_cObject });
+
+    binding_util::call_method(weak_ref_class, "new", Some(vec![object]))
+}
+
+// Raises `WeakRef::RefError` if the object has been collected
+pub fn get(weak_ref: Value) -> Result<Value, Value> {
+    vm::protect_value(|| binding_util::call_method(weak_ref, "__getobj__", None))
+}
+
+pub fn is_alive(weak_ref: Value) -> bool {
+    binding_util::call_method(weak_ref, "weakref_alive?", None).is_true()
+}
+
+// The `WeakRef` constant does not exist until the `weakref` library is loaded, so there can not
+// be any weak references before it
+pub fn is_weak_ref(object: Value) -> bool {
+    let object_class = unsafe { rb_cObject };
+    let name = binding_util::internal_id("WeakRef");
+
+    if unsafe { rb_const_defined(object_class, name) } == 0 {
+        return false;
+    }
+
+    let weak_ref_class = binding_util::get_constant("WeakRef", object_class);
+
+    unsafe { rb_obj_is_kind_of(object, weak_ref_class) }.is_true()
+}
@@ -1,6 +1,9 @@
-use types::Value;
+use std::fmt;
 
-use {Object, VerifiedObject};
+use result::{Error, Result};
+use types::{Value, ValueType};
+
+use {Array, Class, Fixnum, Hash, Integer, Object, RString, VerifiedObject};
 
 /// Representation of any Ruby object while its type is unknown
 ///
@@ -48,11 +51,328 @@ use {Object, VerifiedObject};
 /// ```
 ///
 /// You can find more examples in `Class`, `Object` and `VerifiedObject` documentation.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, PartialEq)]
 pub struct AnyObject {
     value: Value,
 }
 
+impl AnyObject {
+    /// Navigates through nested objects by following the given `path`.
+    ///
+    /// Each segment of the path is applied to the result of the previous one:
+    ///
+    /// - `DigSegment::Method(name)` calls a method without arguments;
+    ///
+    /// - `DigSegment::Index(index)` and `DigSegment::Key(key)` call `[]`.
+    ///
+    /// All the calls are performed using `protect_send()`. If a call raises an exception or a
+    /// lookup in the middle of the path returns `nil`, an error describing the failed segment is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{AnyObject, Array, DigSegment, Fixnum, Hash, Object, RString, Symbol, VM};
+    /// # VM::init();
+    ///
+    /// let mut user = Hash::new();
+    /// user.store(Symbol::new("name"), RString::new("Alice"));
+    ///
+    /// let mut config = Hash::new();
+    /// config.store(Symbol::new("users"), Array::new().push(user));
+    ///
+    /// let config = config.to_any_object();
+    ///
+    /// let name_length = config.dig(&[
+    ///     DigSegment::Key(Symbol::new("users").to_any_object()),
+    ///     DigSegment::Index(0),
+    ///     DigSegment::Key(Symbol::new("name").to_any_object()),
+    ///     DigSegment::Method("length"),
+    /// ]);
+    ///
+    /// assert_eq!(name_length.unwrap().try_convert_to::<Fixnum>(), Ok(Fixnum::new(5)));
+    ///
+    /// let error = config.dig(&[
+    ///     DigSegment::Key(Symbol::new("admins").to_any_object()),
+    ///     DigSegment::Index(0),
+    /// ]).unwrap_err();
+    ///
+    /// assert_eq!(error.to_string(), "Failed to dig at segment 0 `[:admins]`: got nil");
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// config = { users: [{ name: 'Alice' }] }
+    ///
+    /// config[:users][0][:name].length == 5
+    ///
+    /// config.dig(:admins, 0) == nil
+    /// ```
+    pub fn dig(&self, path: &[DigSegment]) -> Result<AnyObject> {
+        let mut current = self.clone();
+
+        for (index, segment) in path.iter().enumerate() {
+            let describe_failure = |message: &str| {
+                format!("Failed to dig at segment {} `{}`: {}", index, segment.describe(), message)
+            };
+
+            let result = match *segment {
+                DigSegment::Method(method) => current.protect_send(method, &[]),
+                DigSegment::Index(position) => {
+                    current.protect_send("[]", &[Fixnum::new(position).to_any_object()])
+                }
+                DigSegment::Key(ref key) => current.protect_send("[]", &[key.clone()]),
+            };
+
+            current = match result {
+                Ok(object) => object,
+                Err(Error::Exception(exception, message)) => {
+                    return Err(Error::Exception(exception, describe_failure(&message)));
+                }
+                Err(error) => return Err(error),
+            };
+
+            let is_last = index + 1 == path.len();
+
+            if current.is_nil() && !is_last {
+                let message = describe_failure("got nil");
+
+                return Err(match *segment {
+                    DigSegment::Method(_) => Error::NoMethodError { message: message },
+                    DigSegment::Index(_) => Error::IndexError { message: message },
+                    DigSegment::Key(_) => Error::KeyError { message: message },
+                });
+            }
+        }
+
+        Ok(current)
+    }
+
+    /// Casts the object to the given type if it has the correct type.
+    ///
+    /// Unlike `try_convert_to()`, no error is created when the type does not match, so it is
+    /// cheaper when `None` is an expected outcome.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Fixnum, Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let object = Fixnum::new(1).to_any_object();
+    ///
+    /// assert_eq!(object.downcast::<Fixnum>(), Some(Fixnum::new(1)));
+    /// assert_eq!(object.downcast::<RString>(), None);
+    /// ```
+    pub fn downcast<T: VerifiedObject>(&self) -> Option<T> {
+        if T::is_correct_type(self) {
+            Some(T::from(self.value()))
+        } else {
+            None
+        }
+    }
+
+    /// Checks whether the object can be cast to the given type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Fixnum, Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let object = Fixnum::new(1).to_any_object();
+    ///
+    /// assert!(object.is::<Fixnum>());
+    /// assert!(!object.is::<RString>());
+    /// ```
+    pub fn is<T: VerifiedObject>(&self) -> bool {
+        T::is_correct_type(self)
+    }
+
+    /// Converts the object to `RString` using the implicit conversion protocol (`to_str`).
+    ///
+    /// Strings are returned as is. Other objects are converted with `to_str` if they implement
+    /// it, otherwise `TypeError` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Class, Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let code = RString::new("def to_str; 'path/to/file'; end");
+    ///
+    /// let class = Class::new("Path", None);
+    /// class.protect_send("class_eval", &[code.to_any_object()]).unwrap();
+    ///
+    /// let path = class.new_instance(None);
+    /// let object = Class::from_existing("Object").new_instance(None);
+    ///
+    /// assert_eq!(path.coerce_to_rstring().unwrap().to_str(), "path/to/file");
+    /// assert_eq!(
+    ///     object.coerce_to_rstring().unwrap_err().to_string(),
+    ///     "Object does not implement `to_str`"
+    /// );
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// class Path
+    ///   def to_str
+    ///     'path/to/file'
+    ///   end
+    /// end
+    ///
+    /// String.try_convert(Path.new) == 'path/to/file'
+    /// String.try_convert(Object.new) == nil
+    /// ```
+    pub fn coerce_to_rstring(&self) -> Result<RString> {
+        self.try_convert_with("String", "to_str")
+    }
+
+    /// Converts the object to `Array` using the implicit conversion protocol (`to_ary`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Array, Fixnum, Object, VM};
+    /// # VM::init();
+    ///
+    /// let array = Array::new().push(Fixnum::new(1)).to_any_object();
+    ///
+    /// assert_eq!(array.coerce_to_array().unwrap().length(), 1);
+    /// assert!(Fixnum::new(1).to_any_object().coerce_to_array().is_err());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Array.try_convert([1]) == [1]
+    /// Array.try_convert(1) == nil
+    /// ```
+    pub fn coerce_to_array(&self) -> Result<Array> {
+        self.try_convert_with("Array", "to_ary")
+    }
+
+    /// Converts the object to `Hash` using the implicit conversion protocol (`to_hash`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Hash, Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let hash = Hash::new().to_any_object();
+    ///
+    /// assert_eq!(hash.coerce_to_hash().unwrap().length(), 0);
+    /// assert!(RString::new("").to_any_object().coerce_to_hash().is_err());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Hash.try_convert({}) == {}
+    /// Hash.try_convert('') == nil
+    /// ```
+    pub fn coerce_to_hash(&self) -> Result<Hash> {
+        self.try_convert_with("Hash", "to_hash")
+    }
+
+    /// Converts the object to `Integer` using the implicit conversion protocol (`to_int`).
+    ///
+    /// Returns `TypeError` if the object does not implement `to_int` or if `to_int` does not
+    /// return an integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Float, Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let float = Float::new(2.5).to_any_object();
+    ///
+    /// assert_eq!(float.coerce_to_integer().unwrap().to_i64(), 2);
+    /// assert!(RString::new("2").to_any_object().coerce_to_integer().is_err());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// 2.5.to_int == 2
+    /// '2'.respond_to?(:to_int) == false
+    /// ```
+    pub fn coerce_to_integer(&self) -> Result<Integer> {
+        if Self::is_integer(self) {
+            return Ok(Integer::from(self.value()));
+        }
+
+        if !self.respond_to("to_int") {
+            return Err(self.protocol_error("to_int"));
+        }
+
+        let result = self.protect_send("to_int", &[])?;
+
+        if Self::is_integer(&result) {
+            Ok(Integer::from(result.value()))
+        } else {
+            let class_name = self.class().name();
+
+            Err(Error::TypeError(format!(
+                "can't convert {} to Integer ({}#to_int gives {})",
+                class_name,
+                class_name,
+                result.class().name()
+            )))
+        }
+    }
+
+    // `String.try_convert` and friends return `nil` when the object does not implement the
+    // conversion method and raise `TypeError` when the method returns an object of wrong type
+    fn try_convert_with<T: Object>(&self, class_name: &str, method: &str) -> Result<T> {
+        let result = Class::from_existing(class_name).protect_send("try_convert", &[self.clone()])?;
+
+        if result.is_nil() {
+            Err(self.protocol_error(method))
+        } else {
+            Ok(T::from(result.value()))
+        }
+    }
+
+    fn protocol_error(&self, method: &str) -> Error {
+        Error::TypeError(format!("{} does not implement `{}`", self.class().name(), method))
+    }
+
+    fn is_integer(object: &AnyObject) -> bool {
+        match object.ty() {
+            ValueType::Fixnum | ValueType::Bignum => true,
+            _ => false,
+        }
+    }
+}
+
+/// A segment of the path for `AnyObject::dig()`
+#[derive(Clone, Debug, PartialEq)]
+pub enum DigSegment<'a> {
+    /// Calls a method without arguments (`object.method`)
+    Method(&'a str),
+    /// Retrieves an element by index (`object[index]`)
+    Index(i64),
+    /// Retrieves an element by key (`object[key]`)
+    Key(AnyObject),
+}
+
+impl<'a> DigSegment<'a> {
+    fn describe(&self) -> String {
+        match *self {
+            DigSegment::Method(method) => format!(".{}", method),
+            DigSegment::Index(index) => format!("[{}]", index),
+            DigSegment::Key(ref key) => format!("[{}]", key.inspect()),
+        }
+    }
+}
+
 impl From<Value> for AnyObject {
     fn from(value: Value) -> Self {
         AnyObject { value: value }
@@ -66,6 +386,12 @@ impl Object for AnyObject {
     }
 }
 
+impl fmt::Debug for AnyObject {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.inspect())
+    }
+}
+
 // Any object can be safely converted to `AnyObject` :)
 impl VerifiedObject for AnyObject {
     fn is_correct_type<T: Object>(_: &T) -> bool {
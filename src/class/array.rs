@@ -1,14 +1,17 @@
-use std::convert::From;
+use std::cmp::Ordering;
+use std::convert::{From, TryFrom};
 use std::default::Default;
+use std::fmt;
 use std::iter::{FromIterator, IntoIterator, Iterator};
 
-use binding::array;
+use binding::{array, fixnum};
+use result::Error;
 use types::{Value, ValueType};
 
-use {AnyObject, Object, RString, VerifiedObject};
+use {AnyObject, Fixnum, Hash, Object, RString, VerifiedObject, VM};
 
 /// `Array`
-#[derive(Debug, PartialEq)]
+#[derive(PartialEq)]
 pub struct Array {
     value: Value,
 }
@@ -178,6 +181,44 @@ impl Array {
         Array::from(result)
     }
 
+    /// Pushes all the elements of the slice to the end of the array.
+    ///
+    /// Unlike `concat()`, it receives a Rust slice instead of a Ruby `Array`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Array, Fixnum, Object, VM};
+    /// # VM::init();
+    ///
+    /// let mut array = Array::new().push(Fixnum::new(1));
+    ///
+    /// array.push_all(&[Fixnum::new(2), Fixnum::new(3), Fixnum::new(4)]);
+    ///
+    /// assert_eq!(array.length(), 4);
+    ///
+    /// for i in 0..4 {
+    ///     assert_eq!(array.at(i).try_convert_to::<Fixnum>(), Ok(Fixnum::new(i + 1)));
+    /// }
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// array = [1]
+    ///
+    /// array.push(2, 3, 4)
+    ///
+    /// array == [1, 2, 3, 4]
+    /// ```
+    pub fn push_all<T: Object>(&mut self, items: &[T]) -> &mut Self {
+        for item in items {
+            array::push(self.value(), item.value());
+        }
+
+        self
+    }
+
     /// Stores an object at `index` position.
     ///
     /// # Examples
@@ -505,6 +546,537 @@ impl Array {
         let result = array::sort_bang(self.value());
         Array::from(result)
     }
+
+    /// Sorts the array in place using a Rust comparator.
+    ///
+    /// The elements are copied to a `Vec`, sorted with `slice::sort_by()` and stored back to
+    /// the array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    ///
+    /// use ruru::{Array, Fixnum, Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let mut array = Array::new()
+    ///     .push(RString::new("banana"))
+    ///     .push(Fixnum::new(1))
+    ///     .push(RString::new("apple"));
+    ///
+    /// // Strings are sorted alphabetically, incomparable objects are moved to the end
+    /// array.sort_by_rust(|a, b| {
+    ///     a.compare(b).ok().and_then(|ordering| ordering).unwrap_or_else(|| {
+    ///         if a.is::<RString>() { Ordering::Less } else { Ordering::Greater }
+    ///     })
+    /// });
+    ///
+    /// assert_eq!(array.at(0).try_convert_to::<RString>().unwrap().to_str(), "apple");
+    /// assert_eq!(array.at(1).try_convert_to::<RString>().unwrap().to_str(), "banana");
+    /// assert_eq!(array.at(2).try_convert_to::<Fixnum>(), Ok(Fixnum::new(1)));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// array = ['banana', 1, 'apple']
+    ///
+    /// array.sort! do |a, b|
+    ///   (a <=> b) || (a.is_a?(String) ? -1 : 1)
+    /// end
+    ///
+    /// array == ['apple', 'banana', 1]
+    /// ```
+    pub fn sort_by_rust<F>(&mut self, compare: F) -> &mut Self
+    where
+        F: FnMut(&AnyObject, &AnyObject) -> Ordering,
+    {
+        let mut elements: Vec<AnyObject> = Array::from(self.value()).into_iter().collect();
+
+        elements.sort_by(compare);
+
+        for (index, element) in elements.into_iter().enumerate() {
+            self.store(index as i64, element);
+        }
+
+        self
+    }
+
+    /// Returns a new array with elements sorted as `i64` numbers.
+    ///
+    /// The elements are converted to `i64` and sorted in Rust without calling Ruby `<=>` for each
+    /// pair of elements, which is faster than `sort()` for arrays of integers (see the
+    /// `sort_fixnum` example for a benchmark).
+    ///
+    /// Returns `Err` if any element is not a `Fixnum`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Array, Fixnum, Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let array = Array::new().push(Fixnum::new(3)).push(Fixnum::new(-1)).push(Fixnum::new(2));
+    ///
+    /// let sorted = array.sort_fixnum().unwrap();
+    ///
+    /// assert_eq!(sorted.at(0).try_convert_to::<Fixnum>(), Ok(Fixnum::new(-1)));
+    /// assert_eq!(sorted.at(1).try_convert_to::<Fixnum>(), Ok(Fixnum::new(2)));
+    /// assert_eq!(sorted.at(2).try_convert_to::<Fixnum>(), Ok(Fixnum::new(3)));
+    ///
+    /// let mixed = Array::new().push(Fixnum::new(1)).push(RString::new("2"));
+    ///
+    /// assert!(mixed.sort_fixnum().is_err());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// [3, -1, 2].sort == [-1, 2, 3]
+    /// ```
+    pub fn sort_fixnum(&self) -> Result<Array, Error> {
+        let mut numbers = Array::from(self.value())
+            .into_iter()
+            .map(|element| element.try_convert_to::<Fixnum>().map(|number| number.to_i64()))
+            .collect::<Result<Vec<i64>, Error>>()?;
+
+        numbers.sort();
+
+        let sorted = numbers
+            .into_iter()
+            .map(|number| Fixnum::new(number).to_any_object())
+            .collect();
+
+        Ok(sorted)
+    }
+
+    /// Returns the sum of elements of the array.
+    ///
+    /// `Array#sum` is used in Ruby 2.4 and newer. In older versions the elements are added with
+    /// `+` one by one starting from `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Array, Fixnum, Float, Object, VM};
+    /// # VM::init();
+    ///
+    /// let integers = Array::new().push(Fixnum::new(1)).push(Fixnum::new(2));
+    /// let floats = Array::new().push(Float::new(1.5)).push(Fixnum::new(2));
+    ///
+    /// assert_eq!(integers.sum().try_convert_to::<Fixnum>(), Ok(Fixnum::new(3)));
+    /// assert_eq!(floats.sum().try_convert_to::<Float>(), Ok(Float::new(3.5)));
+    /// assert_eq!(Array::new().sum().try_convert_to::<Fixnum>(), Ok(Fixnum::new(0)));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// [1, 2].sum == 3
+    /// [1.5, 2].sum == 3.5
+    /// [].sum == 0
+    /// ```
+    pub fn sum(&self) -> AnyObject {
+        let result = if VM::ruby_version_at_least(2, 4, 0) {
+            array::sum(self.value())
+        } else {
+            array::sum_fallback(self.value())
+        };
+
+        AnyObject::from(result)
+    }
+
+    /// Returns the minimum element of the array or `nil` if the array is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Array, Fixnum, Object, VM};
+    /// # VM::init();
+    ///
+    /// let array = Array::new().push(Fixnum::new(2)).push(Fixnum::new(1)).push(Fixnum::new(3));
+    ///
+    /// assert_eq!(array.min().try_convert_to::<Fixnum>(), Ok(Fixnum::new(1)));
+    /// assert!(Array::new().min().is_nil());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// [2, 1, 3].min == 1
+    /// [].min == nil
+    /// ```
+    pub fn min(&self) -> AnyObject {
+        let result = array::min(self.value());
+
+        AnyObject::from(result)
+    }
+
+    /// Returns the maximum element of the array or `nil` if the array is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Array, Fixnum, Object, VM};
+    /// # VM::init();
+    ///
+    /// let array = Array::new().push(Fixnum::new(2)).push(Fixnum::new(3)).push(Fixnum::new(1));
+    ///
+    /// assert_eq!(array.max().try_convert_to::<Fixnum>(), Ok(Fixnum::new(3)));
+    /// assert!(Array::new().max().is_nil());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// [2, 3, 1].max == 3
+    /// [].max == nil
+    /// ```
+    pub fn max(&self) -> AnyObject {
+        let result = array::max(self.value());
+
+        AnyObject::from(result)
+    }
+
+    /// Returns the sum of elements of the array as `i64`.
+    ///
+    /// See `sum()` for the supported Ruby versions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sum is not a `Fixnum` (for example, if the array contains floats).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Array, Fixnum, VM};
+    /// # VM::init();
+    ///
+    /// let array = Array::new().push(Fixnum::new(1)).push(Fixnum::new(2));
+    ///
+    /// assert_eq!(array.sum_fixnum(), 3);
+    /// assert_eq!(Array::new().sum_fixnum(), 0);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// [1, 2].sum == 3
+    /// [].sum == 0
+    /// ```
+    pub fn sum_fixnum(&self) -> i64 {
+        match self.sum().try_convert_to::<Fixnum>() {
+            Ok(sum) => sum.to_i64(),
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Returns the minimum element of the array as `i64`.
+    ///
+    /// Returns `None` if the array is empty or the minimum element is not a `Fixnum`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Array, Fixnum, VM};
+    /// # VM::init();
+    ///
+    /// let array = Array::new().push(Fixnum::new(2)).push(Fixnum::new(1));
+    ///
+    /// assert_eq!(array.min_fixnum(), Some(1));
+    /// assert_eq!(Array::new().min_fixnum(), None);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// [2, 1].min == 1
+    /// [].min == nil
+    /// ```
+    pub fn min_fixnum(&self) -> Option<i64> {
+        self.min().try_convert_to::<Fixnum>().ok().map(|min| min.to_i64())
+    }
+
+    /// Returns a new array containing the first `n` elements of the array.
+    ///
+    /// Returns `ArgumentError` if `n` is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Array, Fixnum, Object, VM};
+    /// # VM::init();
+    ///
+    /// let array = Array::new().push(Fixnum::new(1)).push(Fixnum::new(2)).push(Fixnum::new(3));
+    ///
+    /// let taken = array.take(2).unwrap();
+    ///
+    /// assert_eq!(taken.length(), 2);
+    /// assert_eq!(taken.at(0).try_convert_to::<Fixnum>(), Ok(Fixnum::new(1)));
+    /// assert_eq!(taken.at(1).try_convert_to::<Fixnum>(), Ok(Fixnum::new(2)));
+    /// assert_eq!(array.take(5).unwrap().length(), 3);
+    /// assert!(array.take(-1).is_err());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// array = [1, 2, 3]
+    ///
+    /// array.take(2) == [1, 2]
+    /// array.take(5) == [1, 2, 3]
+    /// ```
+    pub fn take(&self, n: i64) -> Result<Array, Error> {
+        Self::check_count(n)?;
+
+        let result = array::take(self.value(), n);
+
+        Ok(Array::from(result))
+    }
+
+    /// Returns a new array containing all but the first `n` elements of the array.
+    ///
+    /// Returns `ArgumentError` if `n` is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Array, Fixnum, Object, VM};
+    /// # VM::init();
+    ///
+    /// let array = Array::new().push(Fixnum::new(1)).push(Fixnum::new(2)).push(Fixnum::new(3));
+    ///
+    /// let rest = array.drop(1).unwrap();
+    ///
+    /// assert_eq!(rest.length(), 2);
+    /// assert_eq!(rest.at(0).try_convert_to::<Fixnum>(), Ok(Fixnum::new(2)));
+    /// assert_eq!(rest.at(1).try_convert_to::<Fixnum>(), Ok(Fixnum::new(3)));
+    /// assert_eq!(array.drop(5).unwrap().length(), 0);
+    /// assert!(array.drop(-1).is_err());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// array = [1, 2, 3]
+    ///
+    /// array.drop(1) == [2, 3]
+    /// array.drop(5) == []
+    /// ```
+    pub fn drop(&self, n: i64) -> Result<Array, Error> {
+        Self::check_count(n)?;
+
+        let result = array::drop(self.value(), n);
+
+        Ok(Array::from(result))
+    }
+
+    fn check_count(n: i64) -> Result<(), Error> {
+        if n < 0 {
+            Err(Error::ArgumentError(format!("attempt to take negative size: {}", n)))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns a new array containing the elements of the array until the `predicate` returns
+    /// `false` for the first time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Array, Fixnum, Object, VM};
+    /// # VM::init();
+    ///
+    /// let array = Array::new()
+    ///     .push(Fixnum::new(1))
+    ///     .push(Fixnum::new(2))
+    ///     .push(Fixnum::new(5))
+    ///     .push(Fixnum::new(1));
+    ///
+    /// let small_numbers = array.take_while(|item| {
+    ///     item.try_convert_to::<Fixnum>().map(|number| number.to_i64() < 3).unwrap_or(false)
+    /// });
+    ///
+    /// assert_eq!(small_numbers.length(), 2);
+    /// assert_eq!(small_numbers.at(0).try_convert_to::<Fixnum>(), Ok(Fixnum::new(1)));
+    /// assert_eq!(small_numbers.at(1).try_convert_to::<Fixnum>(), Ok(Fixnum::new(2)));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// array = [1, 2, 5, 1]
+    ///
+    /// array.take_while { |item| item < 3 } == [1, 2]
+    /// ```
+    pub fn take_while<F>(&self, predicate: F) -> Array
+    where
+        F: Fn(&AnyObject) -> bool,
+    {
+        Array::from(self.value()).into_iter().take_while(|item| predicate(item)).collect()
+    }
+
+    /// Returns the index of the first element which is equal (Ruby `==`) to `item` or `None`
+    /// if there is no such element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Array, Fixnum, RString, VM};
+    /// # VM::init();
+    ///
+    /// let array = Array::new().push(Fixnum::new(1)).push(Fixnum::new(2)).push(Fixnum::new(2));
+    ///
+    /// assert_eq!(array.index_of(&Fixnum::new(2)), Some(1));
+    /// assert_eq!(array.index_of(&Fixnum::new(3)), None);
+    /// assert_eq!(array.index_of(&RString::new("1")), None);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// array = [1, 2, 2]
+    ///
+    /// array.index(2) == 1
+    /// array.index(3) == nil
+    /// array.index('1') == nil
+    /// ```
+    pub fn index_of<T: Object>(&self, item: &T) -> Option<usize> {
+        let result = array::index(self.value(), item.value());
+
+        if result.is_nil() {
+            None
+        } else {
+            Some(fixnum::num_to_int(result) as usize)
+        }
+    }
+
+    /// Returns the index of the first element for which the `predicate` returns `true` or `None`
+    /// if there is no such element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{AnyObject, Array, Fixnum, Object, VM};
+    /// # VM::init();
+    ///
+    /// let array = Array::new().push(Fixnum::new(1)).push(Fixnum::new(4)).push(Fixnum::new(6));
+    ///
+    /// let is_even = |item: &AnyObject| {
+    ///     item.try_convert_to::<Fixnum>().map(|number| number.to_i64() % 2 == 0).unwrap_or(false)
+    /// };
+    ///
+    /// assert_eq!(array.find_index(is_even), Some(1));
+    /// assert_eq!(array.find_index(|item| item.is_nil()), None);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// array = [1, 4, 6]
+    ///
+    /// array.find_index { |item| item.even? } == 1
+    /// array.find_index { |item| item.nil? } == nil
+    /// ```
+    pub fn find_index<F>(&self, predicate: F) -> Option<usize>
+    where
+        F: Fn(&AnyObject) -> bool,
+    {
+        Array::from(self.value()).into_iter().position(|item| predicate(&item))
+    }
+
+    /// Counts occurrences of each element of the array.
+    ///
+    /// Returns a `Hash` with elements as keys and their counts as values. `Array#tally` is used
+    /// on Ruby 2.7 and newer, the counting is done in Rust on older versions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Array, Fixnum, Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let array = Array::new()
+    ///     .push(RString::new("a"))
+    ///     .push(RString::new("b"))
+    ///     .push(RString::new("a"));
+    ///
+    /// let tally = array.tally();
+    ///
+    /// assert_eq!(tally.length(), 2);
+    /// assert_eq!(tally.at(&RString::new("a")).try_convert_to::<Fixnum>(), Ok(Fixnum::new(2)));
+    /// assert_eq!(tally.at(&RString::new("b")).try_convert_to::<Fixnum>(), Ok(Fixnum::new(1)));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// ['a', 'b', 'a'].tally == { 'a' => 2, 'b' => 1 }
+    /// ```
+    pub fn tally(&self) -> Hash {
+        let result = if VM::ruby_version_at_least(2, 7, 0) {
+            array::tally(self.value())
+        } else {
+            array::tally_fallback(self.value())
+        };
+
+        Hash::from(result)
+    }
+
+    /// Returns a new array with the concatenated results of running `closure` once for every
+    /// element of the array (Ruby `Enumerable#flat_map`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Array, Fixnum, Object, VM};
+    /// # VM::init();
+    ///
+    /// let array = Array::new()
+    ///     .push(Array::new().push(Fixnum::new(1)).push(Fixnum::new(2)))
+    ///     .push(Array::new().push(Fixnum::new(3)).push(Fixnum::new(4)));
+    ///
+    /// let result = array.flat_map(|item| {
+    ///     item.try_convert_to::<Array>()
+    ///         .unwrap()
+    ///         .into_iter()
+    ///         .map(|number| {
+    ///             let number = number.try_convert_to::<Fixnum>().unwrap().to_i64();
+    ///
+    ///             Fixnum::new(number + 1).to_any_object()
+    ///         })
+    ///         .collect()
+    /// });
+    ///
+    /// let numbers: Vec<i64> = result
+    ///     .into_iter()
+    ///     .map(|number| number.try_convert_to::<Fixnum>().unwrap().to_i64())
+    ///     .collect();
+    ///
+    /// assert_eq!(numbers, vec![2, 3, 4, 5]);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// array = [[1, 2], [3, 4]]
+    ///
+    /// array.flat_map { |item| item.map { |number| number + 1 } } == [2, 3, 4, 5]
+    /// ```
+    pub fn flat_map<F>(&self, closure: F) -> Array
+    where
+        F: Fn(AnyObject) -> Array,
+    {
+        let mut result = Array::new();
+
+        for item in Array::from(self.value()) {
+            result.concat(&closure(item));
+        }
+
+        result
+    }
 }
 
 impl Default for Array {
@@ -519,6 +1091,40 @@ impl From<Value> for Array {
     }
 }
 
+/// Converts a `Vec` of Ruby objects into `Array`.
+///
+/// # Examples
+///
+/// ```
+/// use ruru::{Array, Fixnum, Object, VM};
+/// # VM::init();
+///
+/// let array = Array::from(vec![Fixnum::new(1), Fixnum::new(2)]);
+///
+/// assert_eq!(array.length(), 2);
+/// assert_eq!(array.at(0).try_convert_to::<Fixnum>(), Ok(Fixnum::new(1)));
+/// assert_eq!(array.at(1).try_convert_to::<Fixnum>(), Ok(Fixnum::new(2)));
+/// ```
+///
+/// Ruby:
+///
+/// ```ruby
+/// array = [1, 2]
+///
+/// array.length == 2
+/// array[0] == 1
+/// array[1] == 2
+/// ```
+impl<T: Object> From<Vec<T>> for Array {
+    fn from(items: Vec<T>) -> Self {
+        let mut array = Array::with_capacity(items.len());
+
+        array.push_all(&items);
+
+        array
+    }
+}
+
 impl Object for Array {
     #[inline]
     fn value(&self) -> Value {
@@ -526,6 +1132,12 @@ impl Object for Array {
     }
 }
 
+impl fmt::Debug for Array {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.inspect())
+    }
+}
+
 impl VerifiedObject for Array {
     fn is_correct_type<T: Object>(object: &T) -> bool {
         object.value().ty() == ValueType::Array
@@ -536,6 +1148,20 @@ impl VerifiedObject for Array {
     }
 }
 
+impl TryFrom<AnyObject> for Array {
+    type Error = Error;
+
+    fn try_from(object: AnyObject) -> Result<Self, Error> {
+        object.try_convert_to::<Array>()
+    }
+}
+
+impl From<Array> for AnyObject {
+    fn from(object: Array) -> Self {
+        object.to_any_object()
+    }
+}
+
 pub struct ArrayIterator {
     array: Array,
     current_index: i64,
@@ -1,12 +1,14 @@
-use std::convert::From;
+use std::convert::{From, TryFrom};
+use std::fmt;
 
+use result::Error;
 use types::Value;
 use util;
 
-use {Object, VerifiedObject};
+use {AnyObject, Object, VerifiedObject};
 
 /// `TrueClass` and `FalseClass`
-#[derive(Debug, PartialEq)]
+#[derive(PartialEq)]
 pub struct Boolean {
     value: Value,
 }
@@ -66,6 +68,12 @@ impl Object for Boolean {
     }
 }
 
+impl fmt::Debug for Boolean {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.inspect())
+    }
+}
+
 impl VerifiedObject for Boolean {
     fn is_correct_type<T: Object>(object: &T) -> bool {
         let value = object.value();
@@ -77,3 +85,17 @@ impl VerifiedObject for Boolean {
         "Error converting to Boolean"
     }
 }
+
+impl TryFrom<AnyObject> for Boolean {
+    type Error = Error;
+
+    fn try_from(object: AnyObject) -> Result<Self, Error> {
+        object.try_convert_to::<Boolean>()
+    }
+}
+
+impl From<Boolean> for AnyObject {
+    fn from(object: Boolean) -> Self {
+        object.to_any_object()
+    }
+}
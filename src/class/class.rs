@@ -1,13 +1,15 @@
-use std::convert::From;
+use std::convert::{From, TryFrom};
+use std::fmt;
 
-use binding::class;
+use binding::{class, vm};
 use binding::global::rb_cObject;
 use binding::util as binding_util;
+use result::Error;
 use typed_data::DataTypeWrapper;
-use types::{Value, ValueType};
+use types::{AllocCallback, Value, ValueType};
 use util;
 
-use {AnyObject, Array, Object, VerifiedObject};
+use {AnyObject, Array, ClassBuilder, Module, Object, RString, UnboundMethod, VerifiedObject, VM};
 
 /// `Class`
 ///
@@ -56,7 +58,7 @@ use {AnyObject, Array, Object, VerifiedObject};
 ///   end
 /// end
 /// ```
-#[derive(Debug, PartialEq)]
+#[derive(PartialEq)]
 pub struct Class {
     value: Value,
 }
@@ -106,8 +108,78 @@ impl Class {
         Self::from(class::define_class(name, superclass))
     }
 
+    /// Returns a builder which defines a new `Class` with a chain of method definitions.
+    ///
+    /// It is an alternative to `Class::new()` followed by `define()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate ruru;
+    ///
+    /// use ruru::{Class, Fixnum, Object, RString, VM};
+    ///
+    /// class!(Greeter);
+    ///
+    /// methods!(
+    ///     Greeter,
+    ///     itself,
+    ///
+    ///     fn greeting() -> RString {
+    ///         RString::new("Hello")
+    ///     }
+    ///
+    ///     fn greeting_count() -> Fixnum {
+    ///         Fixnum::new(1)
+    ///     }
+    /// );
+    ///
+    /// fn main() {
+    ///     # VM::init();
+    ///     let class = Class::build("Greeter", None)
+    ///         .method("greeting", greeting)
+    ///         .method("greeting_count", greeting_count)
+    ///         .finish();
+    ///
+    ///     let greeter = class.new_instance(None);
+    ///
+    ///     let greeting = greeter
+    ///         .protect_send("greeting", &[])
+    ///         .unwrap()
+    ///         .try_convert_to::<RString>();
+    ///     let count = greeter
+    ///         .protect_send("greeting_count", &[])
+    ///         .unwrap()
+    ///         .try_convert_to::<Fixnum>();
+    ///
+    ///     assert_eq!(greeting.unwrap().to_str(), "Hello");
+    ///     assert_eq!(count, Ok(Fixnum::new(1)));
+    /// }
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// class Greeter
+    ///   def greeting
+    ///     'Hello'
+    ///   end
+    ///
+    ///   def greeting_count
+    ///     1
+    ///   end
+    /// end
+    /// ```
+    pub fn build(name: &str, superclass: Option<&Self>) -> ClassBuilder {
+        ClassBuilder::new(name, superclass)
+    }
+
     /// Retrieves an existing `Class` object.
     ///
+    /// Raises `NameError` if the constant is not defined. Use `try_from_existing()` if the class
+    /// may be missing.
+    ///
     /// # Examples
     ///
     /// ```
@@ -139,6 +211,97 @@ impl Class {
         Self::from(binding_util::get_constant(name, object_class))
     }
 
+    /// Retrieves an existing `Class` object if it is defined.
+    ///
+    /// Unlike `from_existing()`, it can be used to integrate with optional libraries which may
+    /// not be loaded. Nested classes like `Foo::Bar` are resolved one segment at a time.
+    ///
+    /// Returns `Error::NameError` if the constant is not defined and `Error::TypeError` if the
+    /// constant is defined, but it is not a class.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::result::Error;
+    /// use ruru::{Class, VM};
+    /// # VM::init();
+    ///
+    /// let class = Class::new("Record", None);
+    ///
+    /// assert_eq!(Class::try_from_existing("Record"), Ok(class));
+    ///
+    /// assert_eq!(
+    ///     Class::try_from_existing("Encoding::CompatibilityError"),
+    ///     Ok(Class::from_existing("Encoding").get_nested_class("CompatibilityError"))
+    /// );
+    ///
+    /// match Class::try_from_existing("ActiveRecord") {
+    ///     Err(Error::NameError { .. }) => {},
+    ///     _ => panic!("NameError expected"),
+    /// }
+    ///
+    /// match Class::try_from_existing("Encoding::ActiveRecord") {
+    ///     Err(Error::NameError { .. }) => {},
+    ///     _ => panic!("NameError expected"),
+    /// }
+    ///
+    /// assert_eq!(
+    ///     Class::try_from_existing("Comparable"),
+    ///     Err(Error::TypeError("Comparable is not a class".to_string()))
+    /// );
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// class Record
+    /// end
+    ///
+    /// Object.const_get('Record') # Record
+    /// Object.const_get('Encoding::CompatibilityError') # Encoding::CompatibilityError
+    ///
+    /// Object.const_get('ActiveRecord') # raises NameError
+    /// Object.const_get('Encoding::ActiveRecord') # raises NameError
+    ///
+    /// Object.const_get('Comparable').is_a?(Class) == false
+    /// ```
+    pub fn try_from_existing(name: &str) -> Result<Self, Error> {
+        let mut constant = unsafe { rb_cObject };
+        let mut resolved = String::new();
+
+        for segment in name.split("::") {
+            // Constants can only be looked up inside of classes and modules
+            if constant.ty() != ValueType::Class && constant.ty() != ValueType::Module {
+                return Err(Error::TypeError(format!("{} is not a class/module", resolved)));
+            }
+
+            let parent = constant;
+
+            constant = vm::protect_value(|| binding_util::get_constant(segment, parent))
+                .map_err(|exception| {
+                    match Error::from_exception(AnyObject::from(exception)) {
+                        Error::Exception(ref exception, ref message)
+                            if exception.is_a_named("NameError") => {
+                            Error::NameError { message: message.clone() }
+                        }
+                        error => error,
+                    }
+                })?;
+
+            if !resolved.is_empty() {
+                resolved.push_str("::");
+            }
+
+            resolved.push_str(segment);
+        }
+
+        if constant.ty() == ValueType::Class {
+            Ok(Self::from(constant))
+        } else {
+            Err(Error::TypeError(format!("{} is not a class", name)))
+        }
+    }
+
     /// Creates a new instance of `Class`
     ///
     /// Arguments must be passed as a vector of `AnyObject` (see example).
@@ -174,6 +337,100 @@ impl Class {
         AnyObject::from(instance)
     }
 
+    /// Creates a new instance of `Class` with protection
+    ///
+    /// If `initialize` raises an exception, it is returned as `Error::Exception` instead of being
+    /// propagated to Ruby.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Class, Fixnum, Object, RString, Symbol, VM};
+    /// # VM::init();
+    ///
+    /// let members = [Symbol::new("x").to_any_object(), Symbol::new("y").to_any_object()];
+    /// let point_class = Class::from_existing("Struct")
+    ///     .protect_send("new", &members)
+    ///     .unwrap()
+    ///     .try_convert_to::<Class>()
+    ///     .unwrap();
+    ///
+    /// let coordinates = [Fixnum::new(3).to_any_object(), Fixnum::new(4).to_any_object()];
+    /// let point = point_class.protect_new_instance(&coordinates).unwrap();
+    ///
+    /// let y = point.protect_send("y", &[]).unwrap().try_convert_to::<Fixnum>().unwrap();
+    ///
+    /// assert_eq!(y.to_i64(), 4);
+    ///
+    /// let code = RString::new("def initialize; raise ArgumentError, 'Invalid record'; end");
+    ///
+    /// let mut record_class = Class::new("InvalidRecord", None);
+    /// record_class.protect_send("class_eval", &[code.to_any_object()]).unwrap();
+    ///
+    /// let error = record_class.protect_new_instance(&[]).unwrap_err();
+    ///
+    /// assert_eq!(error.to_exception(), Class::from_existing("ArgumentError"));
+    /// assert_eq!(error.to_string(), "Invalid record");
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Point = Struct.new(:x, :y)
+    ///
+    /// Point.new(3, 4).y == 4
+    ///
+    /// class InvalidRecord
+    ///   def initialize
+    ///     raise ArgumentError, 'Invalid record'
+    ///   end
+    /// end
+    ///
+    /// begin
+    ///   InvalidRecord.new
+    /// rescue ArgumentError => error
+    ///   error.message == 'Invalid record'
+    /// end
+    /// ```
+    pub fn protect_new_instance(&self, arguments: &[AnyObject]) -> Result<AnyObject, Error> {
+        let klass = self.value();
+        let arguments = util::arguments_to_values(Some(arguments));
+
+        vm::protect_value(|| class::new_instance(klass, arguments))
+            .map(AnyObject::from)
+            .map_err(|exception| Error::from_exception(AnyObject::from(exception)))
+    }
+
+    /// Returns the name of the current class
+    ///
+    /// For anonymous classes the result of `Class#inspect` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Class, VM};
+    /// # VM::init();
+    ///
+    /// assert_eq!(Class::from_existing("Array").name(), "Array");
+    /// assert_eq!(Class::new("Record", None).name(), "Record");
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Array.name == 'Array'
+    ///
+    /// class Record
+    /// end
+    ///
+    /// Record.name == 'Record'
+    /// ```
+    pub fn name(&self) -> String {
+        let name = class::name(self.value());
+
+        RString::from(name).to_string()
+    }
+
     /// Returns a superclass of the current class
     ///
     /// # Examples
@@ -199,6 +456,208 @@ impl Class {
         }
     }
 
+    /// Returns an instance method of the class which can be bound to any instance of the class
+    ///
+    /// Returns `Error::Exception` with `NameError` if the method is not defined.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Array, Class, Fixnum, Object, VM};
+    /// # VM::init();
+    ///
+    /// let array_class = Class::from_existing("Array");
+    /// let length = array_class.instance_method("length").unwrap();
+    ///
+    /// let array = Array::new().push(Fixnum::new(1));
+    /// let result = length.bind(array.to_any_object()).call(&[]);
+    ///
+    /// assert_eq!(result.try_convert_to::<Fixnum>(), Ok(Fixnum::new(1)));
+    ///
+    /// let error = array_class.instance_method("undefined_method").unwrap_err();
+    ///
+    /// assert_eq!(error.to_exception(), Class::from_existing("NameError"));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// length = Array.instance_method(:length)
+    ///
+    /// length.bind([1]).call == 1
+    ///
+    /// Array.instance_method(:undefined_method) # raises NameError
+    /// ```
+    pub fn instance_method(&self, name: &str) -> Result<UnboundMethod, Error> {
+        let klass = self.value();
+
+        vm::protect_value(|| class::instance_method(klass, name))
+            .map(UnboundMethod::from)
+            .map_err(|exception| Error::from_exception(AnyObject::from(exception)))
+    }
+
+    /// Checks whether a public or protected instance method is defined in the class or in its
+    /// ancestors (Ruby `method_defined?`).
+    ///
+    /// Use it to define a method only if it is not defined yet, for example, when the host
+    /// application already patched a core class.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate ruru;
+    ///
+    /// use ruru::{Class, Object, RString, VM};
+    ///
+    /// methods!(
+    ///     RString,
+    ///     itself,
+    ///
+    ///     fn shout() -> RString {
+    ///         RString::new(&itself.to_str().to_uppercase())
+    ///     }
+    /// );
+    ///
+    /// fn main() {
+    ///     # VM::init();
+    ///     let mut string_class = Class::from_existing("String");
+    ///
+    ///     assert!(!string_class.method_defined("shout"));
+    ///
+    ///     string_class.define(|itself| {
+    ///         if !itself.method_defined("shout") {
+    ///             itself.def("shout", shout);
+    ///         }
+    ///     });
+    ///
+    ///     assert!(string_class.method_defined("shout"));
+    ///     assert!(string_class.method_defined("length"));
+    /// }
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// class String
+    ///   unless method_defined?(:shout)
+    ///     def shout
+    ///       upcase
+    ///     end
+    ///   end
+    /// end
+    ///
+    /// String.method_defined?(:shout) == true
+    /// ```
+    pub fn method_defined(&self, name: &str) -> bool {
+        class::is_method_defined(self.value(), name, "method_defined?")
+    }
+
+    /// Checks whether a public instance method is defined in the class or in its ancestors
+    /// (Ruby `public_method_defined?`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Class, VM};
+    /// # VM::init();
+    ///
+    /// let object_class = Class::from_existing("Object");
+    ///
+    /// assert!(object_class.public_method_defined("to_s"));
+    /// assert!(!object_class.public_method_defined("puts"));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Object.public_method_defined?(:to_s) == true
+    /// Object.public_method_defined?(:puts) == false
+    /// ```
+    pub fn public_method_defined(&self, name: &str) -> bool {
+        class::is_method_defined(self.value(), name, "public_method_defined?")
+    }
+
+    /// Checks whether a private instance method is defined in the class or in its ancestors
+    /// (Ruby `private_method_defined?`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Class, VM};
+    /// # VM::init();
+    ///
+    /// let object_class = Class::from_existing("Object");
+    ///
+    /// assert!(object_class.private_method_defined("puts"));
+    /// assert!(!object_class.private_method_defined("to_s"));
+    /// assert!(!object_class.private_method_defined("undefined_method"));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Object.private_method_defined?(:puts) == true
+    /// Object.private_method_defined?(:to_s) == false
+    /// ```
+    pub fn private_method_defined(&self, name: &str) -> bool {
+        class::is_method_defined(self.value(), name, "private_method_defined?")
+    }
+
+    /// Returns names of public and protected instance methods of the class
+    ///
+    /// When `include_inherited` is `false`, only the methods defined in the class itself are
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate ruru;
+    ///
+    /// use ruru::{Class, Fixnum, Object, VM};
+    ///
+    /// class!(Counter);
+    ///
+    /// methods!(
+    ///     Counter,
+    ///     itself,
+    ///
+    ///     fn counter_state() -> Fixnum {
+    ///         Fixnum::new(0)
+    ///     }
+    /// );
+    ///
+    /// fn main() {
+    ///     # VM::init();
+    ///     let counter_class = Class::new("Counter", None).define(|itself| {
+    ///         itself.def("state", counter_state);
+    ///     });
+    ///
+    ///     let all_methods = counter_class.instance_methods(true);
+    ///
+    ///     assert_eq!(counter_class.instance_methods(false), vec!["state".to_string()]);
+    ///     assert!(all_methods.contains(&"state".to_string()));
+    ///     assert!(all_methods.contains(&"to_s".to_string()));
+    /// }
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// class Counter
+    ///   def state
+    ///     0
+    ///   end
+    /// end
+    ///
+    /// Counter.instance_methods(false) == [:state]
+    /// Counter.instance_methods.include?(:to_s) == true
+    /// ```
+    pub fn instance_methods(&self, include_inherited: bool) -> Vec<String> {
+        class::instance_methods(self.value(), include_inherited)
+    }
+
     /// Returns a Vector of ancestors of current class
     ///
     /// # Examples
@@ -244,6 +703,67 @@ impl Class {
             .collect()
     }
 
+    /// Checks whether the module is included into the class or into one of its ancestors
+    /// (Ruby `include?`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Class, Module, VM};
+    /// # VM::init();
+    ///
+    /// let comparable = Module::from_existing("Comparable");
+    ///
+    /// assert!(Class::from_existing("String").includes_module(&comparable));
+    /// assert!(!Class::from_existing("Array").includes_module(&comparable));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// String.include?(Comparable) == true
+    /// Array.include?(Comparable) == false
+    /// ```
+    pub fn includes_module(&self, module: &Module) -> bool {
+        class::includes_module(self.value(), module.value())
+    }
+
+    /// Checks whether the class is a descendant of `other` (Ruby `Class#<`).
+    ///
+    /// Returns `false` if the class is `other` itself or if the classes are unrelated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Class, VM};
+    /// # VM::init();
+    ///
+    /// let integer = Class::from_existing("Integer");
+    /// let numeric = Class::from_existing("Numeric");
+    ///
+    /// assert!(integer.is_subclass_of(&numeric));
+    /// assert!(!numeric.is_subclass_of(&integer));
+    /// assert!(!integer.is_subclass_of(&integer));
+    /// assert!(!integer.is_subclass_of(&Class::from_existing("String")));
+    ///
+    /// // `Fixnum` is merged into `Integer` since Ruby 2.4
+    /// if !VM::ruby_version_at_least(2, 4, 0) {
+    ///     assert!(Class::from_existing("Fixnum").is_subclass_of(&integer));
+    /// }
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// (Integer < Numeric) == true
+    /// (Numeric < Integer) == false
+    /// (Integer < Integer) == false
+    /// (Integer < String) == nil
+    /// ```
+    pub fn is_subclass_of(&self, other: &Class) -> bool {
+        class::is_subclass_of(self.value(), other.value())
+    }
+
     /// Retrieves a `Class` nested to current `Class`.
     ///
     /// # Examples
@@ -320,10 +840,14 @@ impl Class {
 
     /// Retrieves a constant from class.
     ///
+    /// Returns `Error::ArgumentError` if `name` is not a valid constant name and
+    /// `Error::Exception` with `NameError` if the constant is not defined.
+    ///
     /// # Examples
     ///
     /// ```
-    /// use ruru::{Class, Object, RString, VM};
+    /// use ruru::{Class, Float, Object, RString, VM};
+    /// use ruru::result::Error;
     /// # VM::init();
     ///
     /// Class::new("Greeter", None).define(|itself| {
@@ -332,10 +856,28 @@ impl Class {
     ///
     /// let greeting = Class::from_existing("Greeter")
     ///     .const_get("GREETING")
+    ///     .unwrap()
     ///     .try_convert_to::<RString>()
     ///     .unwrap();
     ///
     /// assert_eq!(greeting.to_str(), "Hello, World!");
+    ///
+    /// let infinity = Class::from_existing("Float")
+    ///     .const_get("INFINITY")
+    ///     .unwrap()
+    ///     .try_convert_to::<Float>()
+    ///     .unwrap();
+    ///
+    /// assert!(infinity.to_f64().is_infinite());
+    ///
+    /// let missing = Class::from_existing("Greeter").const_get("FAREWELL").unwrap_err();
+    ///
+    /// assert_eq!(missing.to_exception(), Class::from_existing("NameError"));
+    ///
+    /// match Class::from_existing("Greeter").const_get("greeting") {
+    ///     Err(Error::ArgumentError(_)) => {},
+    ///     _ => panic!("ArgumentError expected"),
+    /// }
     /// ```
     ///
     /// Ruby:
@@ -357,58 +899,311 @@ impl Class {
     /// # or
     ///
     /// Greeter.const_get('GREETING') == 'Hello, World'
+    ///
+    /// Float::INFINITY.infinite? == 1
     /// ```
-    pub fn const_get(&self, name: &str) -> AnyObject {
-        let value = class::const_get(self.value(), name);
+    pub fn const_get(&self, name: &str) -> Result<AnyObject, Error> {
+        util::check_constant_name(name)?;
 
-        AnyObject::from(value)
+        let klass = self.value();
+
+        vm::protect_value(|| class::const_get(klass, name))
+            .map(AnyObject::from)
+            .map_err(|exception| Error::from_exception(AnyObject::from(exception)))
     }
 
     /// Defines a constant for class.
     ///
+    /// Raises `ArgumentError` if `name` is not a valid constant name (it must start with an
+    /// uppercase letter).
+    ///
     /// # Examples
     ///
     /// ```
-    /// use ruru::{Class, Object, RString, VM};
+    /// use ruru::{Class, Fixnum, Object, RString, VM};
     /// # VM::init();
     ///
-    /// Class::new("Greeter", None).define(|itself| {
-    ///     itself.const_set("GREETING", &RString::new("Hello, World!"));
+    /// Class::new("Native", None).define(|itself| {
+    ///     itself.const_set("MAX_BUFFER", &Fixnum::new(4096));
     /// });
     ///
-    /// let greeting = Class::from_existing("Greeter")
-    ///     .const_get("GREETING")
-    ///     .try_convert_to::<RString>()
+    /// let code = RString::new("Native::MAX_BUFFER * 2");
+    ///
+    /// let max_buffer = Class::from_existing("Object")
+    ///     .protect_send("eval", &[code.to_any_object()])
+    ///     .unwrap()
+    ///     .try_convert_to::<Fixnum>()
     ///     .unwrap();
     ///
-    /// assert_eq!(greeting.to_str(), "Hello, World!");
+    /// assert_eq!(max_buffer.to_i64(), 8192);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// class Native
+    ///   MAX_BUFFER = 4096
+    /// end
+    ///
+    /// # or
+    ///
+    /// Native = Class.new
+    /// Native.const_set('MAX_BUFFER', 4096)
+    ///
+    /// # ...
+    ///
+    /// Native::MAX_BUFFER * 2 == 8192
+    /// ```
+    pub fn const_set<T: Object>(&mut self, name: &str, value: &T) {
+        if let Err(error) = util::check_constant_name(name) {
+            VM::raise_error(error);
+        }
+
+        class::const_set(self.value(), name, value.value());
+    }
+
+    /// Checks if a constant is defined in class.
+    ///
+    /// Returns `false` if `name` is not a valid constant name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Class, VM};
+    /// # VM::init();
+    ///
+    /// let float = Class::from_existing("Float");
+    ///
+    /// assert!(float.const_defined("INFINITY"));
+    /// assert!(!float.const_defined("FINITY"));
+    /// assert!(!float.const_defined("infinity"));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Float.const_defined?(:INFINITY) == true
+    /// Float.const_defined?(:FINITY) == false
+    /// ```
+    pub fn const_defined(&self, name: &str) -> bool {
+        util::check_constant_name(name).is_ok() && class::const_defined(self.value(), name)
+    }
+
+    /// Retrieves a class variable (Ruby `class_variable_get`).
+    ///
+    /// Returns `Error::NameError` if `name` is not a valid class variable name (for example, it
+    /// does not start with `@@`) and `Error::Exception` with `NameError` if the variable is not
+    /// defined.
+    ///
+    /// Class instance variables (instance variables of the class object itself) are accessed
+    /// with `instance_variable_get()` and `instance_variable_set()` of `Object` trait.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Class, Fixnum, Object, VM};
+    /// use ruru::result::Error;
+    /// # VM::init();
+    ///
+    /// let mut class = Class::new("Connection", None);
+    ///
+    /// class.cvar_set("@@pool_size", Fixnum::new(5)).unwrap();
+    /// class.instance_variable_set("@timeout", Fixnum::new(30));
+    ///
+    /// let pool_size = class.cvar_get("@@pool_size").unwrap().try_convert_to::<Fixnum>();
+    /// let timeout = class.instance_variable_get("@timeout").try_convert_to::<Fixnum>();
+    ///
+    /// assert_eq!(pool_size, Ok(Fixnum::new(5)));
+    /// assert_eq!(timeout, Ok(Fixnum::new(30)));
+    ///
+    /// let missing = class.cvar_get("@@missing").unwrap_err();
+    ///
+    /// assert_eq!(missing.to_exception(), Class::from_existing("NameError"));
+    ///
+    /// match class.cvar_get("pool_size") {
+    ///     Err(Error::NameError { .. }) => {},
+    ///     _ => panic!("NameError expected"),
+    /// }
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// class Connection
+    ///   @@pool_size = 5
+    ///   @timeout = 30
+    /// end
+    ///
+    /// Connection.class_variable_get(:@@pool_size) == 5
+    /// Connection.instance_variable_get(:@timeout) == 30
+    /// ```
+    pub fn cvar_get(&self, name: &str) -> Result<AnyObject, Error> {
+        util::check_class_variable_name(name)?;
+
+        let klass = self.value();
+
+        vm::protect_value(|| class::class_variable_get(klass, name))
+            .map(AnyObject::from)
+            .map_err(|exception| Error::from_exception(AnyObject::from(exception)))
+    }
+
+    /// Sets a class variable (Ruby `class_variable_set`).
+    ///
+    /// Returns `Error::NameError` if `name` is not a valid class variable name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate ruru;
+    ///
+    /// use ruru::{Class, Fixnum, Object, RString, VM};
+    /// use ruru::result::Error;
+    ///
+    /// class!(Registry);
+    ///
+    /// methods!(
+    ///     Registry,
+    ///     itself,
+    ///
+    ///     fn registry_register() -> Result<Fixnum, Error> {
+    ///         let mut class = Class::from(itself.value());
+    ///
+    ///         let count = class.cvar_get("@@count")?.try_convert_to::<Fixnum>()?;
+    ///         let count = Fixnum::new(count.to_i64() + 1);
+    ///
+    ///         class.cvar_set("@@count", count)?.try_convert_to::<Fixnum>()
+    ///     }
+    /// );
+    ///
+    /// fn main() {
+    ///     # VM::init();
+    ///     Class::new("Registry", None).define(|itself| {
+    ///         itself.cvar_set("@@count", Fixnum::new(0)).unwrap();
+    ///         itself.def_self("register", registry_register);
+    ///     });
+    ///
+    ///     let code = RString::new("Registry.register; Registry.register");
+    ///
+    ///     let count = Class::from_existing("Object")
+    ///         .protect_send("eval", &[code.to_any_object()])
+    ///         .unwrap()
+    ///         .try_convert_to::<Fixnum>();
+    ///
+    ///     assert_eq!(count, Ok(Fixnum::new(2)));
+    /// }
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// class Registry
+    ///   @@count = 0
+    ///
+    ///   def self.register
+    ///     @@count += 1
+    ///   end
+    /// end
+    ///
+    /// Registry.register
+    /// Registry.register == 2
+    /// ```
+    pub fn cvar_set<T: Object>(&mut self, name: &str, value: T) -> Result<AnyObject, Error> {
+        util::check_class_variable_name(name)?;
+
+        let klass = self.value();
+        let value = value.value();
+
+        vm::protect_value(|| class::class_variable_set(klass, name, value))
+            .map(AnyObject::from)
+            .map_err(|exception| Error::from_exception(AnyObject::from(exception)))
+    }
+
+    /// Includes a module into the class (Ruby `include`).
+    ///
+    /// Together with `const_set()`, `attr_accessor()`, `def()` and `define_nested_class()` it
+    /// allows declaring the whole class in a single `define()` block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate ruru;
+    ///
+    /// use ruru::{Boolean, Class, Fixnum, Module, Object, RString, VM};
+    ///
+    /// class!(Version < Object);
+    ///
+    /// methods!(
+    ///     Version,
+    ///     itself,
+    ///
+    ///     fn version_compare(other: Version) -> Fixnum {
+    ///         let major = |version: &Version| {
+    ///             let major = version.protect_send("major", &[]).unwrap();
+    ///
+    ///             major.try_convert_to::<Fixnum>().unwrap().to_i64()
+    ///         };
+    ///
+    ///         let result = major(&itself).cmp(&major(&other.unwrap()));
+    ///
+    ///         Fixnum::new(result as i64)
+    ///     }
+    /// );
+    ///
+    /// fn main() {
+    ///     # VM::init();
+    ///     Class::new("Version", None).define(|itself| {
+    ///         itself.const_set("INITIAL", &Fixnum::new(1));
+    ///         itself.include(&Module::from_existing("Comparable"));
+    ///         itself.attr_accessor(&["major"]);
+    ///         itself.def("<=>", version_compare);
+    ///
+    ///         itself.define_nested_class("Error", Some(&Class::from_existing("StandardError")));
+    ///     });
+    ///
+    ///     let code = RString::new("
+    ///         first = Version.new.tap { |version| version.major = Version::INITIAL }
+    ///         second = Version.new.tap { |version| version.major = 2 }
+    ///
+    ///         first < second && Version::Error.ancestors.include?(StandardError)
+    ///     ");
+    ///
+    ///     let result = Class::from_existing("Object")
+    ///         .protect_send("eval", &[code.to_any_object()])
+    ///         .unwrap()
+    ///         .try_convert_to::<Boolean>();
+    ///
+    ///     assert_eq!(result, Ok(Boolean::new(true)));
+    /// }
     /// ```
     ///
     /// Ruby:
     ///
     /// ```ruby
-    /// class Greeter
-    ///   GREETING = 'Hello, World!'
-    /// end
+    /// class Version
+    ///   INITIAL = 1
     ///
-    /// # or
-    ///
-    /// Greeter = Class.new
-    /// Greeter.const_set('GREETING', 'Hello, World!')
+    ///   include Comparable
     ///
-    /// # ...
-    ///
-    /// Greeter::GREETING == 'Hello, World!'
+    ///   attr_accessor :major
     ///
-    /// # or
+    ///   def <=>(other)
+    ///     major <=> other.major
+    ///   end
     ///
-    /// Greeter.const_get('GREETING') == 'Hello, World'
+    ///   class Error < StandardError
+    ///   end
+    /// end
     /// ```
-    pub fn const_set<T: Object>(&mut self, name: &str, value: &T) {
-        class::const_set(self.value(), name, value.value());
+    pub fn include(&mut self, module: &Module) {
+        class::include_module(self.value(), module.value());
     }
 
-    /// Defines an `attr_reader` for class
+    /// Defines `attr_reader`s for class
+    ///
+    /// The readers are implemented in C by Ruby itself, so there is no need to define them with
+    /// Rust callbacks.
     ///
     /// # Examples
     ///
@@ -417,7 +1212,7 @@ impl Class {
     /// # VM::init();
     ///
     /// Class::new("Test", None).define(|itself| {
-    ///     itself.attr_reader("reader");
+    ///     itself.attr_reader(&["reader", "another_reader"]);
     /// });
     /// ```
     ///
@@ -425,14 +1220,16 @@ impl Class {
     ///
     /// ```ruby
     /// class Test
-    ///   attr_reader :reader
+    ///   attr_reader :reader, :another_reader
     /// end
     /// ```
-    pub fn attr_reader(&mut self, name: &str) {
-        class::define_attribute(self.value(), name, true, false);
+    pub fn attr_reader(&mut self, names: &[&str]) {
+        for name in names {
+            class::define_attribute(self.value(), name, true, false);
+        }
     }
 
-    /// Defines an `attr_writer` for class
+    /// Defines `attr_writer`s for class
     ///
     /// # Examples
     ///
@@ -441,7 +1238,7 @@ impl Class {
     /// # VM::init();
     ///
     /// Class::new("Test", None).define(|itself| {
-    ///     itself.attr_writer("writer");
+    ///     itself.attr_writer(&["writer", "another_writer"]);
     /// });
     /// ```
     ///
@@ -449,35 +1246,55 @@ impl Class {
     ///
     /// ```ruby
     /// class Test
-    ///   attr_writer :writer
+    ///   attr_writer :writer, :another_writer
     /// end
     /// ```
-    pub fn attr_writer(&mut self, name: &str) {
-        class::define_attribute(self.value(), name, false, true);
+    pub fn attr_writer(&mut self, names: &[&str]) {
+        for name in names {
+            class::define_attribute(self.value(), name, false, true);
+        }
     }
 
-    /// Defines an `attr_accessor` for class
+    /// Defines `attr_accessor`s for class
     ///
     /// # Examples
     ///
     /// ```
-    /// use ruru::{Class, Object, VM};
+    /// use ruru::{Class, Fixnum, Object, RString, VM};
     /// # VM::init();
     ///
-    /// Class::new("Test", None).define(|itself| {
-    ///     itself.attr_accessor("accessor");
+    /// Class::new("Point", None).define(|itself| {
+    ///     itself.attr_accessor(&["x", "y"]);
     /// });
+    ///
+    /// let code = RString::new("point = Point.new; point.x = 3; point.y = 4; point.x * point.y");
+    ///
+    /// let result = Class::from_existing("Object")
+    ///     .protect_send("eval", &[code.to_any_object()])
+    ///     .unwrap()
+    ///     .try_convert_to::<Fixnum>()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(result.to_i64(), 12);
     /// ```
     ///
     /// Ruby:
     ///
     /// ```ruby
-    /// class Test
-    ///   attr_accessor :accessor
+    /// class Point
+    ///   attr_accessor :x, :y
     /// end
+    ///
+    /// point = Point.new
+    /// point.x = 3
+    /// point.y = 4
+    ///
+    /// point.x * point.y == 12
     /// ```
-    pub fn attr_accessor(&mut self, name: &str) {
-        class::define_attribute(self.value(), name, true, true);
+    pub fn attr_accessor(&mut self, names: &[&str]) {
+        for name in names {
+            class::define_attribute(self.value(), name, true, true);
+        }
     }
 
     /// Wraps Rust structure into a new Ruby object of the current class.
@@ -572,6 +1389,398 @@ impl Class {
         O::from(value)
     }
 
+    /// Defines an allocation function for the class.
+    ///
+    /// The function is called by `Class#new` (and `Class#allocate`) before `initialize` and
+    /// should return a new object of the class. It is mostly used to wrap a default Rust struct,
+    /// so instances of the class can be created without a custom `new` method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use] extern crate ruru;
+    /// #[macro_use] extern crate lazy_static;
+    ///
+    /// use ruru::{AnyObject, Class, Fixnum, Object, RString, VM};
+    ///
+    /// #[derive(Default)]
+    /// pub struct Counter {
+    ///     count: i64,
+    /// }
+    ///
+    /// wrappable_struct!(Counter, CounterWrapper, COUNTER_WRAPPER);
+    ///
+    /// class!(RubyCounter);
+    ///
+    /// methods!(
+    ///     RubyCounter,
+    ///     itself,
+    ///
+    ///     fn counter_increment() -> Fixnum {
+    ///         let counter = itself.get_data_mut(&*COUNTER_WRAPPER);
+    ///
+    ///         counter.count += 1;
+    ///
+    ///         Fixnum::new(counter.count)
+    ///     }
+    /// );
+    ///
+    /// extern "C" fn counter_alloc(klass: Class) -> AnyObject {
+    ///     klass.wrap_data(Counter::default(), &*COUNTER_WRAPPER)
+    /// }
+    ///
+    /// fn main() {
+    ///     # VM::init();
+    ///     let data_class = Class::from_existing("Data");
+    ///
+    ///     Class::new("RubyCounter", Some(&data_class)).define(|itself| {
+    ///         itself.define_alloc_func(counter_alloc);
+    ///         itself.def("increment", counter_increment);
+    ///     });
+    ///
+    ///     let code = RString::new("RubyCounter.new.tap(&:increment).increment");
+    ///
+    ///     let count = Class::from_existing("Object")
+    ///         .protect_send("eval", &[code.to_any_object()])
+    ///         .unwrap()
+    ///         .try_convert_to::<Fixnum>();
+    ///
+    ///     assert_eq!(count, Ok(Fixnum::new(2)));
+    /// }
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// class RubyCounter
+    ///   def initialize
+    ///     @count = 0
+    ///   end
+    ///
+    ///   def increment
+    ///     @count += 1
+    ///   end
+    /// end
+    ///
+    /// counter = RubyCounter.new
+    /// counter.increment
+    /// counter.increment == 2
+    /// ```
+    pub fn define_alloc_func<O: Object>(&mut self, func: AllocCallback<O>) {
+        class::define_alloc_func(self.value(), func);
+    }
+
+    /// Defines an allocation function which creates objects wrapping `T::default()`.
+    ///
+    /// The actual data should be set in `initialize` with `Object::set_wrapped_data()`. Unlike a
+    /// custom `new` method, it makes `allocate`, `dup` and `clone` work for the class. `dup` and
+    /// `clone` call `initialize_copy` which should copy the data of the original object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use] extern crate ruru;
+    /// #[macro_use] extern crate lazy_static;
+    ///
+    /// use ruru::{AnyObject, Array, Class, Fixnum, NilClass, Object, RString, VM};
+    ///
+    /// #[derive(Clone, Default)]
+    /// pub struct Counter {
+    ///     count: i64,
+    /// }
+    ///
+    /// wrappable_struct!(Counter, CounterWrapper, COUNTER_WRAPPER);
+    ///
+    /// class!(RubyCounter);
+    ///
+    /// methods!(
+    ///     RubyCounter,
+    ///     itself,
+    ///
+    ///     fn counter_initialize(count: Fixnum) -> NilClass {
+    ///         let counter = Counter { count: count.unwrap().to_i64() };
+    ///
+    ///         itself.set_wrapped_data(counter, &*COUNTER_WRAPPER);
+    ///
+    ///         NilClass::new()
+    ///     }
+    ///
+    ///     fn counter_initialize_copy(original: AnyObject) -> NilClass {
+    ///         let counter = original.unwrap().get_data(&*COUNTER_WRAPPER).clone();
+    ///
+    ///         itself.set_wrapped_data(counter, &*COUNTER_WRAPPER);
+    ///
+    ///         NilClass::new()
+    ///     }
+    ///
+    ///     fn counter_increment() -> Fixnum {
+    ///         let counter = itself.get_data_mut(&*COUNTER_WRAPPER);
+    ///
+    ///         counter.count += 1;
+    ///
+    ///         Fixnum::new(counter.count)
+    ///     }
+    ///
+    ///     fn counter_count() -> Fixnum {
+    ///         Fixnum::new(itself.get_data(&*COUNTER_WRAPPER).count)
+    ///     }
+    /// );
+    ///
+    /// fn main() {
+    ///     # VM::init();
+    ///     let data_class = Class::from_existing("Data");
+    ///
+    ///     Class::new("RubyCounter", Some(&data_class)).define(|itself| {
+    ///         itself.define_alloc_func_for(&*COUNTER_WRAPPER);
+    ///
+    ///         itself.def_private("initialize", counter_initialize);
+    ///         itself.def_private("initialize_copy", counter_initialize_copy);
+    ///         itself.def("increment", counter_increment);
+    ///         itself.def("count", counter_count);
+    ///     });
+    ///
+    ///     let code = "counter = RubyCounter.new(1); \
+    ///                 copy = counter.dup; \
+    ///                 copy.increment; \
+    ///                 [counter.count, copy.count, RubyCounter.allocate.count]";
+    ///
+    ///     let counts = Class::from_existing("Object")
+    ///         .protect_send("eval", &[RString::new(code).to_any_object()])
+    ///         .unwrap()
+    ///         .try_convert_to::<Array>()
+    ///         .unwrap();
+    ///
+    ///     assert_eq!(counts.at(0).try_convert_to::<Fixnum>(), Ok(Fixnum::new(1)));
+    ///     assert_eq!(counts.at(1).try_convert_to::<Fixnum>(), Ok(Fixnum::new(2)));
+    ///     assert_eq!(counts.at(2).try_convert_to::<Fixnum>(), Ok(Fixnum::new(0)));
+    /// }
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// class RubyCounter
+    ///   attr_reader :count
+    ///
+    ///   def self.allocate
+    ///     super.tap { |counter| counter.instance_variable_set(:@count, 0) }
+    ///   end
+    ///
+    ///   def initialize(count)
+    ///     @count = count
+    ///   end
+    ///
+    ///   def initialize_copy(original)
+    ///     @count = original.count
+    ///   end
+    ///
+    ///   def increment
+    ///     @count += 1
+    ///   end
+    /// end
+    ///
+    /// counter = RubyCounter.new(1)
+    /// copy = counter.dup
+    /// copy.increment
+    ///
+    /// counter.count == 1
+    /// copy.count == 2
+    /// RubyCounter.allocate.count == 0
+    /// ```
+    pub fn define_alloc_func_for<T: Default>(&mut self, wrapper: &'static DataTypeWrapper<T>) {
+        class::define_typed_alloc_func(self.value(), wrapper);
+    }
+
+    /// Changes the visibility of an existing instance method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Class, Object, RString, Visibility, VM};
+    /// # VM::init();
+    ///
+    /// let mut class = Class::new("Secret", None);
+    ///
+    /// let code = RString::new("def value; 42; end");
+    /// class.protect_send("class_eval", &[code.to_any_object()]).unwrap();
+    ///
+    /// let secret = class.new_instance(None);
+    ///
+    /// class.set_method_visibility("value", Visibility::Private);
+    /// assert!(secret.protect_public_send("value", &[]).is_err());
+    ///
+    /// class.set_method_visibility("value", Visibility::Public);
+    /// assert!(secret.protect_public_send("value", &[]).is_ok());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// class Secret
+    ///   def value
+    ///     42
+    ///   end
+    /// end
+    ///
+    /// Secret.send(:private, :value)
+    /// Secret.send(:public, :value)
+    /// ```
+    pub fn set_method_visibility(&mut self, name: &str, visibility: Visibility) {
+        class::set_method_visibility(self.value(), name, visibility);
+    }
+
+    /// Makes `new_name` a copy of the `original` instance method (Ruby `alias_method`).
+    ///
+    /// Returns `Error::Exception` with `NameError` if `original` method is not defined.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate ruru;
+    ///
+    /// use ruru::{Class, Fixnum, Object, RString, VM};
+    ///
+    /// class!(Collection);
+    ///
+    /// methods!(
+    ///     Collection,
+    ///     itself,
+    ///
+    ///     fn collection_len() -> Fixnum {
+    ///         Fixnum::new(3)
+    ///     }
+    /// );
+    ///
+    /// fn main() {
+    ///     # VM::init();
+    ///     let mut class = Class::new("Collection", None);
+    ///
+    ///     class.def("len", collection_len);
+    ///
+    ///     assert!(class.alias_method("length", "len").is_ok());
+    ///     assert!(class.undef_method("len").is_ok());
+    ///     assert!(class.alias_method("size", "count").is_err());
+    ///
+    ///     let eval = |code: &str| {
+    ///         let code = RString::new(code).to_any_object();
+    ///
+    ///         Class::from_existing("Object").protect_send("eval", &[code])
+    ///     };
+    ///
+    ///     let length = eval("Collection.new.length").unwrap().try_convert_to::<Fixnum>();
+    ///     let len = eval("Collection.new.len").unwrap_err();
+    ///
+    ///     assert_eq!(length, Ok(Fixnum::new(3)));
+    ///     assert_eq!(len.to_exception(), Class::from_existing("NoMethodError"));
+    /// }
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// class Collection
+    ///   def len
+    ///     3
+    ///   end
+    ///
+    ///   alias_method :length, :len
+    ///   undef_method :len
+    /// end
+    ///
+    /// Collection.new.length == 3
+    /// Collection.new.len # raises NoMethodError
+    /// ```
+    pub fn alias_method(&mut self, new_name: &str, original: &str) -> Result<(), Error> {
+        let klass = self.value();
+
+        vm::protect_value(|| class::alias_method(klass, new_name, original))
+            .map(|_| ())
+            .map_err(|exception| Error::from_exception(AnyObject::from(exception)))
+    }
+
+    /// Removes an instance method from the class (Ruby `remove_method`).
+    ///
+    /// Unlike `undef_method()`, the method of the superclass with the same name can still be
+    /// called. Returns `Error::Exception` with `NameError` if the method is not defined in the
+    /// class itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Class, Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let code = RString::new("def to_s; 'Record'; end");
+    ///
+    /// let mut class = Class::new("Record", None);
+    /// class.protect_send("class_eval", &[code.to_any_object()]).unwrap();
+    ///
+    /// assert!(class.remove_method("to_s").is_ok());
+    /// assert!(class.remove_method("to_s").is_err());
+    ///
+    /// assert!(class.new_instance(None).respond_to("to_s"));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// class Record
+    ///   def to_s
+    ///     'Record'
+    ///   end
+    ///
+    ///   remove_method :to_s
+    ///   remove_method :to_s # raises NameError
+    /// end
+    ///
+    /// Record.new.respond_to?(:to_s) == true
+    /// ```
+    pub fn remove_method(&mut self, name: &str) -> Result<(), Error> {
+        let klass = self.value();
+
+        vm::protect_value(|| class::remove_method(klass, name))
+            .map(|_| ())
+            .map_err(|exception| Error::from_exception(AnyObject::from(exception)))
+    }
+
+    /// Prevents the class from responding to calls to the method (Ruby `undef_method`).
+    ///
+    /// Methods with the same name defined in superclasses can not be called either. Returns
+    /// `Error::Exception` with `NameError` if the method is not defined.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Class, Object, VM};
+    /// # VM::init();
+    ///
+    /// let mut class = Class::new("Anonymous", None);
+    ///
+    /// assert!(class.undef_method("to_s").is_ok());
+    /// assert!(class.undef_method("undefined_method").is_err());
+    ///
+    /// assert!(!class.new_instance(None).respond_to("to_s"));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// class Anonymous
+    ///   undef_method :to_s
+    ///   undef_method :undefined_method # raises NameError
+    /// end
+    ///
+    /// Anonymous.new.respond_to?(:to_s) == false
+    /// ```
+    pub fn undef_method(&mut self, name: &str) -> Result<(), Error> {
+        let klass = self.value();
+
+        vm::protect_value(|| class::undef_method(klass, name))
+            .map(|_| ())
+            .map_err(|exception| Error::from_exception(AnyObject::from(exception)))
+    }
+
     fn superclass_to_value(superclass: Option<&Class>) -> Value {
         match superclass {
             Some(class) => class.value(),
@@ -580,6 +1789,14 @@ impl Class {
     }
 }
 
+/// Visibility of a method
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Visibility {
+    Public,
+    Protected,
+    Private,
+}
+
 impl From<Value> for Class {
     fn from(value: Value) -> Self {
         Class { value: value }
@@ -593,6 +1810,12 @@ impl Object for Class {
     }
 }
 
+impl fmt::Debug for Class {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.inspect())
+    }
+}
+
 impl VerifiedObject for Class {
     fn is_correct_type<T: Object>(object: &T) -> bool {
         object.value().ty() == ValueType::Class
@@ -602,3 +1825,17 @@ impl VerifiedObject for Class {
         "Error converting to Class"
     }
 }
+
+impl TryFrom<AnyObject> for Class {
+    type Error = Error;
+
+    fn try_from(object: AnyObject) -> Result<Self, Error> {
+        object.try_convert_to::<Class>()
+    }
+}
+
+impl From<Class> for AnyObject {
+    fn from(object: Class) -> Self {
+        object.to_any_object()
+    }
+}
@@ -0,0 +1,77 @@
+use types::{Callback, Value};
+
+use {Class, Object};
+
+type Definition = Box<Fn(&mut Class)>;
+
+/// A builder which defines a class with a chain of method definitions
+///
+/// The class is created and the methods are defined when `finish()` is called.
+///
+/// See `Class::build()`.
+pub struct ClassBuilder {
+    name: String,
+    superclass: Option<Value>,
+    definitions: Vec<Definition>,
+}
+
+impl ClassBuilder {
+    /// Creates a builder for a class with the given name and superclass.
+    ///
+    /// It is the same as `Class::build()`.
+    pub fn new(name: &str, superclass: Option<&Class>) -> Self {
+        ClassBuilder {
+            name: name.to_string(),
+            superclass: superclass.map(|superclass| superclass.value()),
+            definitions: Vec::new(),
+        }
+    }
+
+    /// Adds an instance method to the class (see `Object::define_method()`).
+    ///
+    /// See the example for `Class::build()`.
+    pub fn method<I, O>(mut self, name: &str, callback: Callback<I, O>) -> Self
+    where
+        I: Object + 'static,
+        O: Object + 'static,
+    {
+        let name = name.to_string();
+
+        self.definitions.push(Box::new(move |class: &mut Class| {
+            class.define_method(&name, callback);
+        }));
+
+        self
+    }
+
+    /// Adds a singleton method to the class (see `Object::define_singleton_method()`).
+    ///
+    /// See the example for `Class::build()`.
+    pub fn singleton_method<I, O>(mut self, name: &str, callback: Callback<I, O>) -> Self
+    where
+        I: Object + 'static,
+        O: Object + 'static,
+    {
+        let name = name.to_string();
+
+        self.definitions.push(Box::new(move |class: &mut Class| {
+            class.define_singleton_method(&name, callback);
+        }));
+
+        self
+    }
+
+    /// Creates the class (or reopens an existing one) and defines all the added methods.
+    ///
+    /// See the example for `Class::build()`.
+    pub fn finish(self) -> Class {
+        let superclass = self.superclass.map(Class::from);
+        let mut class = Class::new(&self.name, superclass.as_ref());
+
+        for definition in &self.definitions {
+            definition(&mut class);
+        }
+
+        class
+    }
+}
@@ -0,0 +1,154 @@
+use std::convert::{From, TryFrom};
+use std::fmt;
+
+use binding::encoding;
+use binding::string;
+use result::Error;
+use types::{Value, ValueType};
+
+use {AnyObject, Object, VerifiedObject};
+
+/// `Encoding`
+#[derive(PartialEq)]
+pub struct Encoding {
+    value: Value,
+}
+
+impl Encoding {
+    /// Returns the `UTF-8` encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Encoding, VM};
+    /// # VM::init();
+    ///
+    /// assert_eq!(Encoding::utf8().name(), "UTF-8");
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Encoding::UTF_8.name == 'UTF-8'
+    /// ```
+    pub fn utf8() -> Self {
+        Self::from(encoding::utf8())
+    }
+
+    /// Returns the `ASCII-8BIT` (also known as `BINARY`) encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Encoding, VM};
+    /// # VM::init();
+    ///
+    /// assert_eq!(Encoding::ascii_8bit().name(), "ASCII-8BIT");
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Encoding::ASCII_8BIT.name == 'ASCII-8BIT'
+    /// ```
+    pub fn ascii_8bit() -> Self {
+        Self::from(encoding::ascii_8bit())
+    }
+
+    /// Finds an encoding by its name or alias.
+    ///
+    /// Returns an error if the encoding does not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Encoding, VM};
+    /// # VM::init();
+    ///
+    /// let shift_jis = Encoding::find("Shift_JIS").unwrap();
+    ///
+    /// assert_eq!(shift_jis.name(), "Shift_JIS");
+    /// assert_eq!(Encoding::find("binary"), Ok(Encoding::ascii_8bit()));
+    /// assert!(Encoding::find("Something else").is_err());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// shift_jis = Encoding.find('Shift_JIS')
+    ///
+    /// shift_jis.name == 'Shift_JIS'
+    /// Encoding.find('binary') == Encoding::ASCII_8BIT
+    ///
+    /// Encoding.find('Something else') # ArgumentError: unknown encoding name - Something else
+    /// ```
+    pub fn find(name: &str) -> Result<Self, Error> {
+        encoding::find(name)
+            .map(Self::from)
+            .map_err(|exception| Error::from_exception(AnyObject::from(exception)))
+    }
+
+    /// Returns the name of the encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Encoding, VM};
+    /// # VM::init();
+    ///
+    /// assert_eq!(Encoding::utf8().name(), "UTF-8");
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Encoding::UTF_8.name == 'UTF-8'
+    /// ```
+    pub fn name(&self) -> String {
+        string::value_to_string(encoding::name(self.value()))
+    }
+}
+
+impl From<Value> for Encoding {
+    fn from(value: Value) -> Self {
+        Encoding { value: value }
+    }
+}
+
+impl Object for Encoding {
+    #[inline]
+    fn value(&self) -> Value {
+        self.value
+    }
+}
+
+impl fmt::Debug for Encoding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.inspect())
+    }
+}
+
+impl VerifiedObject for Encoding {
+    fn is_correct_type<T: Object>(object: &T) -> bool {
+        object.value().ty() == ValueType::Data &&
+            object.class().value() == encoding::encoding_class()
+    }
+
+    fn error_message() -> &'static str {
+        "Error converting to Encoding"
+    }
+}
+
+impl TryFrom<AnyObject> for Encoding {
+    type Error = Error;
+
+    fn try_from(object: AnyObject) -> Result<Self, Error> {
+        object.try_convert_to::<Encoding>()
+    }
+}
+
+impl From<Encoding> for AnyObject {
+    fn from(object: Encoding) -> Self {
+        object.to_any_object()
+    }
+}
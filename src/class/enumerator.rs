@@ -0,0 +1,185 @@
+use std::convert::{From, TryFrom};
+use std::fmt;
+
+use binding::enumerator;
+use result::Error;
+use types::{Value, ValueType};
+
+use {AnyObject, Class, Object, VerifiedObject};
+
+/// `Enumerator`
+///
+/// Enumerators are iterated lazily, each element is produced by Ruby on demand.
+///
+/// # Examples
+///
+/// ```
+/// use ruru::{Array, Enumerator, Fixnum, Object, VM};
+/// # VM::init();
+///
+/// let array = Array::new().push(Fixnum::new(1)).push(Fixnum::new(2)).push(Fixnum::new(3));
+///
+/// let enumerator = array
+///     .protect_send("each", &[])
+///     .unwrap()
+///     .try_convert_to::<Enumerator>()
+///     .unwrap();
+///
+/// let numbers: Vec<i64> = enumerator
+///     .map(|number| number.try_convert_to::<Fixnum>().unwrap().to_i64())
+///     .collect();
+///
+/// assert_eq!(numbers, vec![1, 2, 3]);
+/// ```
+///
+/// Ruby:
+///
+/// ```ruby
+/// enumerator = [1, 2, 3].each
+///
+/// enumerator.to_a == [1, 2, 3]
+/// ```
+#[derive(PartialEq)]
+pub struct Enumerator {
+    value: Value,
+}
+
+impl Enumerator {
+    /// Returns the next element of the enumerator (Ruby `Enumerator#next`).
+    ///
+    /// Returns `Error::StopIteration` when the enumerator is finished. Other exceptions raised
+    /// while producing the element are returned as `Error::Exception`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::result::Error;
+    /// use ruru::{Array, Enumerator, Fixnum, Object, VM};
+    /// # VM::init();
+    ///
+    /// let array = Array::new().push(Fixnum::new(1));
+    ///
+    /// let mut enumerator = array
+    ///     .protect_send("each", &[])
+    ///     .unwrap()
+    ///     .try_convert_to::<Enumerator>()
+    ///     .unwrap();
+    ///
+    /// let first = enumerator.try_next().unwrap();
+    ///
+    /// assert_eq!(first.try_convert_to::<Fixnum>(), Ok(Fixnum::new(1)));
+    ///
+    /// match enumerator.try_next() {
+    ///     Err(Error::StopIteration { .. }) => {},
+    ///     _ => panic!("expected StopIteration"),
+    /// }
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// enumerator = [1].each
+    ///
+    /// enumerator.next == 1
+    /// enumerator.next # StopIteration: iteration reached an end
+    /// ```
+    pub fn try_next(&mut self) -> Result<AnyObject, Error> {
+        enumerator::next(self.value())
+            .map(AnyObject::from)
+            .map_err(|exception| {
+                let exception = AnyObject::from(exception);
+                let is_stop_iteration = exception.class() == Class::from_existing("StopIteration");
+                let error = Error::from_exception(exception);
+
+                if is_stop_iteration {
+                    Error::StopIteration { message: error.to_string() }
+                } else {
+                    error
+                }
+            })
+    }
+}
+
+/// Iterates over the elements of the enumerator.
+///
+/// The iteration stops when the enumerator is finished.
+///
+/// # Panics
+///
+/// Panics if an exception other than `StopIteration` is raised while producing the next
+/// element. Use `Enumerator::try_next()` to handle such exceptions.
+///
+/// # Examples
+///
+/// ```
+/// use std::panic;
+///
+/// use ruru::{Class, Enumerator, Object, RString, VM};
+/// # VM::init();
+///
+/// let code = RString::new("Enumerator.new { |yielder| yielder << 1; raise 'Broken' }");
+///
+/// let enumerator = Class::from_existing("Object")
+///     .protect_send("eval", &[code.to_any_object()])
+///     .and_then(|enumerator| enumerator.try_convert_to::<Enumerator>())
+///     .unwrap();
+///
+/// let result = panic::catch_unwind(panic::AssertUnwindSafe(|| enumerator.count()));
+///
+/// assert!(result.is_err());
+/// ```
+impl Iterator for Enumerator {
+    type Item = AnyObject;
+
+    fn next(&mut self) -> Option<AnyObject> {
+        match self.try_next() {
+            Ok(object) => Some(object),
+            Err(Error::StopIteration { .. }) => None,
+            Err(error) => panic!("{}", error),
+        }
+    }
+}
+
+impl From<Value> for Enumerator {
+    fn from(value: Value) -> Self {
+        Enumerator { value: value }
+    }
+}
+
+impl Object for Enumerator {
+    #[inline]
+    fn value(&self) -> Value {
+        self.value
+    }
+}
+
+impl fmt::Debug for Enumerator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.inspect())
+    }
+}
+
+impl VerifiedObject for Enumerator {
+    fn is_correct_type<T: Object>(object: &T) -> bool {
+        object.value().ty() == ValueType::Data &&
+            object.class() == Class::from_existing("Enumerator")
+    }
+
+    fn error_message() -> &'static str {
+        "Error converting to Enumerator"
+    }
+}
+
+impl TryFrom<AnyObject> for Enumerator {
+    type Error = Error;
+
+    fn try_from(object: AnyObject) -> Result<Self, Error> {
+        object.try_convert_to::<Enumerator>()
+    }
+}
+
+impl From<Enumerator> for AnyObject {
+    fn from(object: Enumerator) -> Self {
+        object.to_any_object()
+    }
+}
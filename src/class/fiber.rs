@@ -0,0 +1,223 @@
+use std::convert::{From, TryFrom};
+use std::fmt;
+
+use binding::{fiber, vm};
+use result::Error;
+use types::{Value, ValueType};
+use util;
+
+use {AnyObject, Class, Object, Proc, VerifiedObject};
+
+/// `Fiber`
+///
+/// Fibers are coroutines which are switched explicitly with `resume()` and `yield_value()`.
+///
+/// Fibers do not release GVL. All of them run in the thread which created them and they can not
+/// be resumed from another thread.
+///
+/// Each fiber has its own machine stack which is much smaller than the stack of a thread (see
+/// `RUBY_FIBER_MACHINE_STACK_SIZE` environment variable). Rust code running inside of a fiber
+/// should avoid deep recursion and large values on the stack.
+///
+/// # Examples
+///
+/// ```
+/// use ruru::{Fiber, Fixnum, Object, Proc, VM};
+/// # VM::init();
+///
+/// let counter = Proc::new_from_closure(|_| {
+///     Fiber::yield_value(&[Fixnum::new(1).to_any_object()]).unwrap();
+///     Fiber::yield_value(&[Fixnum::new(2).to_any_object()]).unwrap();
+///
+///     Fixnum::new(3).to_any_object()
+/// });
+///
+/// let mut fiber = Fiber::new(&counter);
+///
+/// let numbers: Vec<i64> = (0..3)
+///     .map(|_| fiber.resume(&[]).unwrap().try_convert_to::<Fixnum>().unwrap().to_i64())
+///     .collect();
+///
+/// assert_eq!(numbers, vec![1, 2, 3]);
+/// assert!(!fiber.is_alive());
+///
+/// // Resuming a finished fiber raises `FiberError`
+/// assert!(fiber.resume(&[]).is_err());
+/// ```
+///
+/// Ruby:
+///
+/// ```ruby
+/// fiber = Fiber.new do
+///   Fiber.yield(1)
+///   Fiber.yield(2)
+///
+///   3
+/// end
+///
+/// 3.times.map { fiber.resume } == [1, 2, 3]
+/// fiber.alive? == false
+///
+/// begin
+///   fiber.resume
+/// rescue FiberError
+/// end
+/// ```
+#[derive(PartialEq)]
+pub struct Fiber {
+    value: Value,
+}
+
+impl Fiber {
+    /// Creates a new fiber which runs the given proc when it is resumed for the first time.
+    ///
+    /// Arguments of the first `resume()` are passed to the proc.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Fiber, Object, Proc, RString, VM};
+    /// # VM::init();
+    ///
+    /// let echo = Proc::new_from_closure(|arguments| arguments[0].clone());
+    ///
+    /// let mut fiber = Fiber::new(&echo);
+    /// let result = fiber.resume(&[RString::new("Hello").to_any_object()]).unwrap();
+    ///
+    /// assert_eq!(result.try_convert_to::<RString>().unwrap().to_str(), "Hello");
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// fiber = Fiber.new { |argument| argument }
+    ///
+    /// fiber.resume('Hello') == 'Hello'
+    /// ```
+    pub fn new(rproc: &Proc) -> Self {
+        Self::from(fiber::new(rproc.value()))
+    }
+
+    /// Returns the currently running fiber.
+    ///
+    /// Outside of any fiber created by `Fiber::new()`, the root fiber of the thread is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Fiber, VM};
+    /// # VM::init();
+    ///
+    /// assert_eq!(Fiber::current(), Fiber::current());
+    /// assert!(Fiber::current().is_alive());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Fiber.current == Fiber.current
+    /// Fiber.current.alive? == true
+    /// ```
+    pub fn current() -> Self {
+        Self::from(fiber::current())
+    }
+
+    /// Resumes the fiber until it yields or finishes.
+    ///
+    /// Returns the value passed to `yield_value()` or the value returned by the proc when the
+    /// fiber finishes.
+    ///
+    /// Exceptions raised inside of the fiber and `FiberError` for a finished fiber are returned
+    /// as `Error::Exception`.
+    ///
+    /// See the example for `Fiber`.
+    pub fn resume(&mut self, arguments: &[AnyObject]) -> Result<AnyObject, Error> {
+        let fiber = self.value();
+        let arguments = util::arguments_to_values(Some(arguments));
+
+        vm::protect_value(|| fiber::resume(fiber, arguments))
+            .map(AnyObject::from)
+            .map_err(|exception| Error::from_exception(AnyObject::from(exception)))
+    }
+
+    /// Suspends the current fiber and passes control back to the caller of `resume()`.
+    ///
+    /// `arguments` become the result of `resume()`. Returns arguments of the next `resume()`.
+    ///
+    /// Yielding from the root fiber returns `FiberError` as `Error::Exception`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Fiber, VM};
+    /// # VM::init();
+    ///
+    /// assert!(Fiber::yield_value(&[]).is_err());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// begin
+    ///   Fiber.yield
+    /// rescue FiberError
+    /// end
+    /// ```
+    pub fn yield_value(arguments: &[AnyObject]) -> Result<AnyObject, Error> {
+        let arguments = util::arguments_to_values(Some(arguments));
+
+        vm::protect_value(|| fiber::yield_value(arguments))
+            .map(AnyObject::from)
+            .map_err(|exception| Error::from_exception(AnyObject::from(exception)))
+    }
+
+    /// Checks whether the fiber can still be resumed.
+    ///
+    /// See the example for `Fiber`.
+    pub fn is_alive(&self) -> bool {
+        fiber::is_alive(self.value())
+    }
+}
+
+impl From<Value> for Fiber {
+    fn from(value: Value) -> Self {
+        Fiber { value: value }
+    }
+}
+
+impl Object for Fiber {
+    #[inline]
+    fn value(&self) -> Value {
+        self.value
+    }
+}
+
+impl fmt::Debug for Fiber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.inspect())
+    }
+}
+
+impl VerifiedObject for Fiber {
+    fn is_correct_type<T: Object>(object: &T) -> bool {
+        object.value().ty() == ValueType::Data && object.class() == Class::from_existing("Fiber")
+    }
+
+    fn error_message() -> &'static str {
+        "Error converting to Fiber"
+    }
+}
+
+impl TryFrom<AnyObject> for Fiber {
+    type Error = Error;
+
+    fn try_from(object: AnyObject) -> Result<Self, Error> {
+        object.try_convert_to::<Fiber>()
+    }
+}
+
+impl From<Fiber> for AnyObject {
+    fn from(object: Fiber) -> Self {
+        object.to_any_object()
+    }
+}
@@ -1,12 +1,15 @@
-use std::convert::From;
+use std::cmp::Ordering;
+use std::convert::{From, TryFrom};
+use std::fmt;
 
 use binding::fixnum;
+use result::Error;
 use types::{Value, ValueType};
 
-use {Object, VerifiedObject};
+use {AnyObject, Object, RString, VerifiedObject};
 
 /// `Fixnum`
-#[derive(Debug, PartialEq)]
+#[derive(PartialEq)]
 pub struct Fixnum {
     value: Value,
 }
@@ -34,8 +37,95 @@ impl Fixnum {
         Self::from(fixnum::int_to_num(num))
     }
 
+    /// Creates a new `Fixnum` from any `i64` value.
+    ///
+    /// Every `i64` can be represented as a Ruby integer, so the conversion never fails. Values
+    /// which do not fit into a Ruby `Fixnum` (62 bits on 64-bit platforms) are stored as
+    /// `Bignum`, but `to_i64()` still returns the original value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Fixnum, VM};
+    /// # VM::init();
+    ///
+    /// assert_eq!(Fixnum::from_i64_checked(1).to_i64(), 1);
+    /// assert_eq!(Fixnum::from_i64_checked(i64::max_value()).to_i64(), i64::max_value());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// 1 == 1
+    /// 9223372036854775807 == 2**63 - 1
+    /// ```
+    pub fn from_i64_checked(num: i64) -> Self {
+        Self::new(num)
+    }
+
+    /// Converts `AnyObject` to `Fixnum` if it is an integer which fits into `i64`.
+    ///
+    /// Unlike `try_convert_to::<Fixnum>()`, it accepts `Bignum`s in `i64` range. Returns
+    /// `Error::RangeError` for integers out of `i64` range and `Error::TypeError` for
+    /// non-integer objects.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::result::Error;
+    /// use ruru::{Fixnum, Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let number = Fixnum::new(1).to_any_object();
+    /// let huge_number = Fixnum::new(i64::max_value())
+    ///     .protect_send("+", &[number.clone()])
+    ///     .unwrap();
+    ///
+    /// assert_eq!(Fixnum::try_from_any(&number), Ok(Fixnum::new(1)));
+    ///
+    /// match Fixnum::try_from_any(&huge_number) {
+    ///     Err(Error::RangeError(_)) => {},
+    ///     _ => panic!("expected RangeError"),
+    /// }
+    ///
+    /// match Fixnum::try_from_any(&RString::new("1").to_any_object()) {
+    ///     Err(Error::TypeError(_)) => {},
+    ///     _ => panic!("expected TypeError"),
+    /// }
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// 1.between?(-2**63, 2**63 - 1) == true
+    /// (2**63).between?(-2**63, 2**63 - 1) == false
+    /// ```
+    pub fn try_from_any(object: &AnyObject) -> Result<Self, Error> {
+        match object.ty() {
+            ValueType::Fixnum => Ok(Self::from(object.value())),
+            ValueType::Bignum => {
+                let min = Self::new(i64::min_value()).to_any_object();
+                let max = Self::new(i64::max_value()).to_any_object();
+
+                let is_in_range = object
+                    .protect_send("between?", &[min, max])
+                    .map(|result| result.value().is_true())
+                    .unwrap_or(false);
+
+                if is_in_range {
+                    Ok(Self::from(object.value()))
+                } else {
+                    Err(Error::RangeError("bignum too big to convert into 'i64'".to_string()))
+                }
+            }
+            _ => Err(Error::TypeError(Self::error_message().to_string())),
+        }
+    }
+
     /// Retrieves an `i64` value from `Fixnum`.
     ///
+    /// Raises `RangeError` if the integer does not fit into `i64`.
+    ///
     /// # Examples
     ///
     /// ```
@@ -45,6 +135,7 @@ impl Fixnum {
     /// let fixnum = Fixnum::new(1);
     ///
     /// assert_eq!(fixnum.to_i64(), 1);
+    /// assert_eq!(Fixnum::new(i64::min_value()).to_i64(), i64::min_value());
     /// ```
     ///
     /// Ruby:
@@ -55,6 +146,35 @@ impl Fixnum {
     pub fn to_i64(&self) -> i64 {
         fixnum::num_to_int(self.value())
     }
+
+    /// Converts the number to a string in the given base (Ruby `to_s(base)`).
+    ///
+    /// `radix` must be between 2 and 36 inclusive, otherwise `ArgumentError` is raised. Digits
+    /// above 9 are represented by lowercase letters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Fixnum, VM};
+    /// # VM::init();
+    ///
+    /// let fixnum = Fixnum::new(255);
+    ///
+    /// assert_eq!(fixnum.to_string_radix(16).to_str(), "ff");
+    /// assert_eq!(fixnum.to_string_radix(2).to_str(), "11111111");
+    /// assert_eq!(Fixnum::new(-35).to_string_radix(36).to_str(), "-z");
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// 255.to_s(16) == 'ff'
+    /// 255.to_s(2) == '11111111'
+    /// -35.to_s(36) == '-z'
+    /// ```
+    pub fn to_string_radix(&self, radix: u32) -> RString {
+        RString::from(fixnum::to_s(self.value(), radix))
+    }
 }
 
 impl From<Value> for Fixnum {
@@ -63,6 +183,33 @@ impl From<Value> for Fixnum {
     }
 }
 
+/// # Examples
+///
+/// ```
+/// use ruru::{Fixnum, VM};
+/// # VM::init();
+///
+/// let mut numbers = vec![Fixnum::new(3), Fixnum::new(-1), Fixnum::new(2)];
+///
+/// numbers.sort_by(|a, b| a.partial_cmp(b).unwrap());
+///
+/// assert_eq!(numbers, vec![Fixnum::new(-1), Fixnum::new(2), Fixnum::new(3)]);
+/// assert!(Fixnum::new(1) <= Fixnum::new(1));
+/// ```
+///
+/// Ruby:
+///
+/// ```ruby
+/// [3, -1, 2].sort == [-1, 2, 3]
+///
+/// 1 <= 1
+/// ```
+impl PartialOrd for Fixnum {
+    fn partial_cmp(&self, other: &Fixnum) -> Option<Ordering> {
+        self.to_i64().partial_cmp(&other.to_i64())
+    }
+}
+
 impl Object for Fixnum {
     #[inline]
     fn value(&self) -> Value {
@@ -70,6 +217,12 @@ impl Object for Fixnum {
     }
 }
 
+impl fmt::Debug for Fixnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.inspect())
+    }
+}
+
 impl VerifiedObject for Fixnum {
     fn is_correct_type<T: Object>(object: &T) -> bool {
         object.value().ty() == ValueType::Fixnum
@@ -79,3 +232,37 @@ impl VerifiedObject for Fixnum {
         "Error converting to Fixnum"
     }
 }
+
+/// Converts `AnyObject` to `Fixnum` using safe conversion (see `Object::try_convert_to()`).
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+///
+/// use ruru::result::Error;
+/// use ruru::{Fixnum, Object, RString, VM};
+/// # VM::init();
+///
+/// let fixnum = Fixnum::try_from(Fixnum::new(1).to_any_object());
+///
+/// assert_eq!(fixnum, Ok(Fixnum::new(1)));
+///
+/// let string = Fixnum::try_from(RString::new("1").to_any_object());
+/// let expected_error = Error::TypeError("Error converting to Fixnum".to_string());
+///
+/// assert_eq!(string, Err(expected_error));
+/// ```
+impl TryFrom<AnyObject> for Fixnum {
+    type Error = Error;
+
+    fn try_from(object: AnyObject) -> Result<Self, Error> {
+        object.try_convert_to::<Fixnum>()
+    }
+}
+
+impl From<Fixnum> for AnyObject {
+    fn from(object: Fixnum) -> Self {
+        object.to_any_object()
+    }
+}
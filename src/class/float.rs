@@ -1,12 +1,15 @@
-use std::convert::From;
+use std::cmp::Ordering;
+use std::convert::{From, TryFrom};
+use std::f64;
+use std::fmt;
 
 use binding::float;
+use result::Error;
 use types::{Value, ValueType};
 
-use {Object, VerifiedObject};
+use {AnyObject, Object, VerifiedObject};
 
 /// `Float`
-#[derive(Debug, PartialEq)]
 pub struct Float {
     value: Value,
 }
@@ -55,6 +58,111 @@ impl Float {
     pub fn to_f64(&self) -> f64 {
         float::num_to_float(self.value())
     }
+
+    /// Creates a new `Float` which is not a number (Ruby `Float::NAN`).
+    ///
+    /// As in Rust, `NaN` is not equal to any number including itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Float, VM};
+    /// # VM::init();
+    ///
+    /// let nan = Float::nan();
+    ///
+    /// assert!(nan.is_nan());
+    /// assert!(!nan.is_infinite());
+    /// assert!(!Float::new(1.0).is_nan());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// nan = Float::NAN
+    ///
+    /// nan.nan? == true
+    /// nan.infinite? == nil
+    /// 1.0.nan? == false
+    /// ```
+    pub fn nan() -> Self {
+        Self::new(f64::NAN)
+    }
+
+    /// Creates a new `Float` representing positive infinity (Ruby `Float::INFINITY`).
+    ///
+    /// Negative infinity can be created with `Float::new(-f64::INFINITY)`
+    /// (Ruby `-Float::INFINITY`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Float, VM};
+    /// # VM::init();
+    ///
+    /// let infinity = Float::infinity();
+    ///
+    /// assert!(infinity.is_infinite());
+    /// assert!(!infinity.is_nan());
+    /// assert!(infinity.to_f64() > 0.0);
+    /// assert!(Float::new(-1.0 / 0.0).is_infinite());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// infinity = Float::INFINITY
+    ///
+    /// infinity.infinite? == 1
+    /// infinity.nan? == false
+    /// (-1.0 / 0).infinite? == -1
+    /// ```
+    pub fn infinity() -> Self {
+        Self::new(f64::INFINITY)
+    }
+
+    /// Checks whether the float is not a number.
+    ///
+    /// See the example for `nan()`.
+    pub fn is_nan(&self) -> bool {
+        self.to_f64().is_nan()
+    }
+
+    /// Checks whether the float is positive or negative infinity.
+    ///
+    /// Unlike Ruby `Float#infinite?`, it returns `bool` for both infinities. See the example for
+    /// `infinity()`.
+    pub fn is_infinite(&self) -> bool {
+        self.to_f64().is_infinite()
+    }
+
+    /// Checks whether the difference between two floats is not greater than `epsilon`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Float, VM};
+    /// # VM::init();
+    ///
+    /// let sum = Float::new(0.1 + 0.2);
+    /// let expected = Float::new(0.3);
+    ///
+    /// assert!(sum.to_f64() != expected.to_f64());
+    /// assert!(sum.approx_eq(&expected, 1e-9));
+    /// assert!(!sum.approx_eq(&Float::new(0.31), 1e-9));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// sum = 0.1 + 0.2
+    ///
+    /// sum != 0.3
+    /// (sum - 0.3).abs <= 1e-9
+    /// ```
+    pub fn approx_eq(&self, other: &Float, epsilon: f64) -> bool {
+        (self.to_f64() - other.to_f64()).abs() <= epsilon
+    }
 }
 
 impl From<Value> for Float {
@@ -63,6 +171,63 @@ impl From<Value> for Float {
     }
 }
 
+/// Floats are equal if they have the same value, so `NaN` is not equal to itself.
+///
+/// # Examples
+///
+/// ```
+/// use ruru::{Float, VM};
+/// # VM::init();
+///
+/// assert_eq!(Float::new(1.5), Float::new(1.5));
+/// assert!(Float::nan() != Float::nan());
+/// ```
+///
+/// Ruby:
+///
+/// ```ruby
+/// 1.5 == 1.5
+/// Float::NAN != Float::NAN
+/// ```
+impl PartialEq for Float {
+    fn eq(&self, other: &Float) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+/// `NaN` is not ordered with any number, the same as in Ruby.
+///
+/// # Examples
+///
+/// ```
+/// use ruru::{Float, VM};
+/// # VM::init();
+///
+/// let mut numbers = vec![Float::new(2.5), Float::new(-1.0), Float::new(0.5)];
+///
+/// numbers.sort_by(|a, b| a.partial_cmp(b).unwrap());
+///
+/// let numbers: Vec<f64> = numbers.iter().map(Float::to_f64).collect();
+///
+/// assert_eq!(numbers, vec![-1.0, 0.5, 2.5]);
+/// assert!(Float::new(1.5) > Float::new(1.0));
+/// assert_eq!(Float::new(::std::f64::NAN).partial_cmp(&Float::new(1.0)), None);
+/// ```
+///
+/// Ruby:
+///
+/// ```ruby
+/// [2.5, -1.0, 0.5].sort == [-1.0, 0.5, 2.5]
+///
+/// 1.5 > 1.0
+/// (Float::NAN <=> 1.0) == nil
+/// ```
+impl PartialOrd for Float {
+    fn partial_cmp(&self, other: &Float) -> Option<Ordering> {
+        self.to_f64().partial_cmp(&other.to_f64())
+    }
+}
+
 impl Object for Float {
     #[inline]
     fn value(&self) -> Value {
@@ -70,6 +235,12 @@ impl Object for Float {
     }
 }
 
+impl fmt::Debug for Float {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.inspect())
+    }
+}
+
 impl VerifiedObject for Float {
     fn is_correct_type<T: Object>(object: &T) -> bool {
         object.value().ty() == ValueType::Float
@@ -79,3 +250,17 @@ impl VerifiedObject for Float {
         "Error converting to Float"
     }
 }
+
+impl TryFrom<AnyObject> for Float {
+    type Error = Error;
+
+    fn try_from(object: AnyObject) -> Result<Self, Error> {
+        object.try_convert_to::<Float>()
+    }
+}
+
+impl From<Float> for AnyObject {
+    fn from(object: Float) -> Self {
+        object.to_any_object()
+    }
+}
@@ -1,5 +1,7 @@
 use binding::gc;
 
+use types::Value;
+
 use Object;
 
 /// Garbage collection
@@ -8,17 +10,133 @@ pub struct GC;
 impl GC {
     /// Mark an object for garbage collection.
     ///
+    /// This function must be called from the `mark` block of `wrappable_struct!` for each Ruby
+    /// object stored in the wrapped Rust structure. Otherwise Ruby does not know that the object
+    /// is still referenced and it may be garbage collected. Raw `Value`s can be marked with
+    /// `VM::gc_mark()`, `Object::mark_for_gc()` is a shortcut for this function.
+    ///
+    /// The contract of the `mark` block:
+    ///
+    ///  - it is called by Ruby during the mark phase of garbage collection only;
+    ///  - it must mark every Ruby object which is reachable from the wrapped structure;
+    ///  - it must not allocate new Ruby objects or call Ruby methods.
+    ///
     /// # Examples
     ///
     /// ```
-    /// use ruru::{Fixnum, GC, VM};
-    /// # VM::init();
+    /// #[macro_use] extern crate ruru;
+    /// #[macro_use] extern crate lazy_static;
+    ///
+    /// use std::sync::atomic::{AtomicBool, Ordering};
+    ///
+    /// use ruru::{AnyObject, Array, Class, GC, Object, RString, VM};
+    ///
+    /// static MARKED: AtomicBool = AtomicBool::new(false);
+    ///
+    /// pub struct Inventory {
+    ///     items: Array,
+    /// }
+    ///
+    /// wrappable_struct! {
+    ///     Inventory,
+    ///     InventoryWrapper,
+    ///     INVENTORY_WRAPPER,
+    ///
+    ///     mark(data) {
+    ///         MARKED.store(true, Ordering::SeqCst);
+    ///
+    ///         GC::mark(&data.items);
+    ///     }
+    /// }
+    ///
+    /// fn main() {
+    ///     # VM::init();
+    ///     let data_class = Class::from_existing("Data");
+    ///     let inventory_class = Class::new("Inventory", Some(&data_class));
+    ///
+    ///     let items = Array::new().push(RString::new(&"hammer".repeat(10)));
+    ///     let inventory: AnyObject =
+    ///         inventory_class.wrap_data(Inventory { items: items }, &*INVENTORY_WRAPPER);
+    ///
+    ///     VM::gc_start();
+    ///
+    ///     // Ruby called the `mark` block during garbage collection
+    ///     assert!(MARKED.load(Ordering::SeqCst));
     ///
-    /// let object = Fixnum::new(1);
+    ///     let items = &inventory.get_data(&*INVENTORY_WRAPPER).items;
+    ///     let item = items.at(0).try_convert_to::<RString>().unwrap();
     ///
-    /// GC::mark(&object);
+    ///     assert_eq!(items.length(), 1);
+    ///     assert_eq!(item.to_str(), "hammer".repeat(10));
+    /// }
     /// ```
     pub fn mark<T: Object>(object: &T) {
         gc::mark(object.value());
     }
 }
+
+/// Keeps a Ruby object alive while the guard exists
+///
+/// Objects which are stored only in Rust (for example, in a static or in a long-lived Rust
+/// struct) are not visible to Ruby garbage collector and may be collected while they are still
+/// used. `GcGuard` registers the object as a GC root on creation and unregisters it on drop.
+///
+/// # Examples
+///
+/// ```
+/// use ruru::{Array, GcGuard, Object, RString, VM};
+/// # VM::init();
+///
+/// let guard = GcGuard::new(RString::new(&format!("Hello, {}!", "World")).value());
+///
+/// for _ in 0..1000 {
+///     Array::new().push(RString::new("Garbage"));
+/// }
+///
+/// VM::gc_start();
+///
+/// let string = RString::from(guard.value());
+///
+/// assert_eq!(string.to_str(), "Hello, World!");
+/// ```
+///
+/// Ruby:
+///
+/// ```ruby
+/// # Ruby objects referenced from Ruby code are never collected
+/// $greeting = "Hello, #{'World'}!"
+///
+/// GC.start
+///
+/// $greeting == 'Hello, World!'
+/// ```
+pub struct GcGuard {
+    // The address of the value must not change while it is registered
+    value: Box<Value>,
+}
+
+impl GcGuard {
+    /// Registers `value` as a GC root.
+    ///
+    /// See the example for `GcGuard`.
+    pub fn new(value: Value) -> Self {
+        let mut value = Box::new(value);
+
+        gc::register_address(&mut *value);
+
+        GcGuard { value: value }
+    }
+
+    /// Returns the guarded value.
+    ///
+    /// See the example for `GcGuard`.
+    pub fn value(&self) -> Value {
+        *self.value
+    }
+}
+
+impl Drop for GcGuard {
+    fn drop(&mut self) {
+        gc::unregister_address(&mut *self.value);
+    }
+}
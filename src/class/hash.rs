@@ -1,13 +1,15 @@
-use std::convert::From;
+use std::convert::{From, TryFrom};
 use std::default::Default;
+use std::fmt;
 
 use binding::hash;
+use result::Error;
 use types::{Value, ValueType};
 
 use {AnyObject, Object, VerifiedObject};
 
 /// `Hash`
-#[derive(Debug, PartialEq)]
+#[derive(PartialEq)]
 pub struct Hash {
     value: Value,
 }
@@ -33,6 +35,110 @@ impl Hash {
         Self::from(hash::new())
     }
 
+    /// Creates a new instance of empty `Hash` with a default value.
+    ///
+    /// The default value is returned by `Hash::at()` for missing keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Fixnum, Hash, Object, Symbol, VM};
+    /// # VM::init();
+    ///
+    /// let mut hash = Hash::new_with_default(Fixnum::new(0));
+    ///
+    /// hash.store(Symbol::new("key"), Fixnum::new(1));
+    ///
+    /// assert_eq!(hash.at(&Symbol::new("key")).try_convert_to::<Fixnum>(), Ok(Fixnum::new(1)));
+    ///
+    /// let missing = hash.at(&Symbol::new("missing_key"));
+    ///
+    /// assert_eq!(missing.try_convert_to::<Fixnum>(), Ok(Fixnum::new(0)));
+    /// assert_eq!(hash.length(), 1);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// hash = Hash.new(0)
+    ///
+    /// hash[:key] = 1
+    ///
+    /// hash[:key] == 1
+    /// hash[:missing_key] == 0
+    /// hash.length == 1
+    /// ```
+    pub fn new_with_default<T: Object>(default: T) -> Self {
+        let hash = hash::new();
+
+        hash::set_default(hash, default.value());
+
+        Self::from(hash)
+    }
+
+    /// Creates a new instance of `Hash` from an iterator of key-value pairs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Fixnum, Hash, Object, RString, Symbol, VM};
+    /// # VM::init();
+    ///
+    /// let pairs = vec![
+    ///     (Symbol::new("width"), Fixnum::new(640)),
+    ///     (Symbol::new("height"), Fixnum::new(480)),
+    /// ];
+    ///
+    /// let hash = Hash::from_pairs(pairs);
+    ///
+    /// let width = hash.at(&Symbol::new("width"));
+    /// let height = hash.at(&Symbol::new("height"));
+    ///
+    /// assert_eq!(hash.length(), 2);
+    /// assert_eq!(width.try_convert_to::<Fixnum>(), Ok(Fixnum::new(640)));
+    /// assert_eq!(height.try_convert_to::<Fixnum>(), Ok(Fixnum::new(480)));
+    ///
+    /// let pairs = vec![("a", 1), ("b", 2)];
+    ///
+    /// let hash = Hash::from_pairs(
+    ///     pairs.into_iter().map(|(key, value)| (RString::new(key), Fixnum::new(value))),
+    /// );
+    ///
+    /// let b = hash.at(&RString::new("b"));
+    ///
+    /// assert_eq!(hash.length(), 2);
+    /// assert_eq!(b.try_convert_to::<Fixnum>(), Ok(Fixnum::new(2)));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// hash = Hash[[[:width, 640], [:height, 480]]]
+    ///
+    /// hash.length == 2
+    /// hash[:width] == 640
+    /// hash[:height] == 480
+    ///
+    /// hash = Hash[[['a', 1], ['b', 2]]]
+    ///
+    /// hash.length == 2
+    /// hash['b'] == 2
+    /// ```
+    pub fn from_pairs<I, K, V>(pairs: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Object,
+        V: Object,
+    {
+        let mut hash = Self::new();
+
+        for (key, value) in pairs {
+            hash.store(key, value);
+        }
+
+        hash
+    }
+
     /// Retrieves an `AnyObject` from element stored at `key` key.
     ///
     /// # Examples
@@ -202,6 +308,99 @@ impl Hash {
         AnyObject::from(result)
     }
 
+    /// Adds the contents of `other` hash to the current hash.
+    ///
+    /// Unlike `Hash#merge`, it modifies the current hash. Values of duplicate keys are
+    /// overwritten with the values from `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Fixnum, Hash, Object, Symbol, VM};
+    /// # VM::init();
+    ///
+    /// let mut hash = Hash::new();
+    /// let mut other = Hash::new();
+    ///
+    /// hash.store(Symbol::new("key1"), Fixnum::new(1));
+    /// hash.store(Symbol::new("key2"), Fixnum::new(2));
+    /// other.store(Symbol::new("key2"), Fixnum::new(3));
+    /// other.store(Symbol::new("key3"), Fixnum::new(4));
+    ///
+    /// hash.update(&other);
+    ///
+    /// assert_eq!(hash.length(), 3);
+    /// assert_eq!(hash.at(&Symbol::new("key1")).try_convert_to::<Fixnum>(), Ok(Fixnum::new(1)));
+    /// assert_eq!(hash.at(&Symbol::new("key2")).try_convert_to::<Fixnum>(), Ok(Fixnum::new(3)));
+    /// assert_eq!(hash.at(&Symbol::new("key3")).try_convert_to::<Fixnum>(), Ok(Fixnum::new(4)));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// hash = { key1: 1, key2: 2 }
+    /// other = { key2: 3, key3: 4 }
+    ///
+    /// hash.update(other)
+    ///
+    /// hash == { key1: 1, key2: 3, key3: 4 }
+    /// ```
+    pub fn update(&mut self, other: &Hash) {
+        hash::update(self.value(), other.value());
+    }
+
+    /// Adds the contents of `other` hash to the current hash using a closure to merge values of
+    /// duplicate keys.
+    ///
+    /// The closure receives the key, the value from the current hash and the value from `other`.
+    /// Its result is stored as the new value of the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{AnyObject, Fixnum, Hash, Object, Symbol, VM};
+    /// # VM::init();
+    ///
+    /// let mut hash = Hash::new();
+    /// let mut other = Hash::new();
+    ///
+    /// hash.store(Symbol::new("key1"), Fixnum::new(1));
+    /// hash.store(Symbol::new("key2"), Fixnum::new(2));
+    /// other.store(Symbol::new("key2"), Fixnum::new(3));
+    /// other.store(Symbol::new("key3"), Fixnum::new(4));
+    ///
+    /// hash.update_with(&other, |_key, old_value, new_value| {
+    ///     let old_value = old_value.try_convert_to::<Fixnum>().unwrap();
+    ///     let new_value = new_value.try_convert_to::<Fixnum>().unwrap();
+    ///
+    ///     Fixnum::new(old_value.to_i64() + new_value.to_i64()).to_any_object()
+    /// });
+    ///
+    /// assert_eq!(hash.length(), 3);
+    /// assert_eq!(hash.at(&Symbol::new("key1")).try_convert_to::<Fixnum>(), Ok(Fixnum::new(1)));
+    /// assert_eq!(hash.at(&Symbol::new("key2")).try_convert_to::<Fixnum>(), Ok(Fixnum::new(5)));
+    /// assert_eq!(hash.at(&Symbol::new("key3")).try_convert_to::<Fixnum>(), Ok(Fixnum::new(4)));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// hash = { key1: 1, key2: 2 }
+    /// other = { key2: 3, key3: 4 }
+    ///
+    /// hash.update(other) do |_key, old_value, new_value|
+    ///   old_value + new_value
+    /// end
+    ///
+    /// hash == { key1: 1, key2: 5, key3: 4 }
+    /// ```
+    pub fn update_with<F>(&mut self, other: &Hash, closure: F)
+    where
+        F: FnMut(AnyObject, AnyObject, AnyObject) -> AnyObject,
+    {
+        hash::update_with(self.value(), other.value(), closure);
+    }
+
     /// Runs a closure for each `key` and `value` pair.
     ///
     /// Key and value have `AnyObject` type.
@@ -279,6 +478,12 @@ impl Object for Hash {
     }
 }
 
+impl fmt::Debug for Hash {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.inspect())
+    }
+}
+
 impl VerifiedObject for Hash {
     fn is_correct_type<T: Object>(object: &T) -> bool {
         object.value().ty() == ValueType::Hash
@@ -288,3 +493,17 @@ impl VerifiedObject for Hash {
         "Error converting to Hash"
     }
 }
+
+impl TryFrom<AnyObject> for Hash {
+    type Error = Error;
+
+    fn try_from(object: AnyObject) -> Result<Self, Error> {
+        object.try_convert_to::<Hash>()
+    }
+}
+
+impl From<Hash> for AnyObject {
+    fn from(object: Hash) -> Self {
+        object.to_any_object()
+    }
+}
@@ -1,12 +1,14 @@
-use std::convert::From;
+use std::convert::{From, TryFrom};
+use std::fmt;
 
 use binding::fixnum;
+use result::Error;
 use types::{Value, ValueType};
 
-use {Object, VerifiedObject};
+use {AnyObject, Object, VerifiedObject};
 
 /// `Integer`
-#[derive(Debug, PartialEq)]
+#[derive(PartialEq)]
 pub struct Integer {
     value: Value,
 }
@@ -70,6 +72,12 @@ impl Object for Integer {
     }
 }
 
+impl fmt::Debug for Integer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.inspect())
+    }
+}
+
 impl VerifiedObject for Integer {
     fn is_correct_type<T: Object>(object: &T) -> bool {
         object.value().ty() == ValueType::Fixnum
@@ -79,3 +87,17 @@ impl VerifiedObject for Integer {
         "Error converting to Integer"
     }
 }
+
+impl TryFrom<AnyObject> for Integer {
+    type Error = Error;
+
+    fn try_from(object: AnyObject) -> Result<Self, Error> {
+        object.try_convert_to::<Integer>()
+    }
+}
+
+impl From<Integer> for AnyObject {
+    fn from(object: Integer) -> Self {
+        object.to_any_object()
+    }
+}
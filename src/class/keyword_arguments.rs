@@ -0,0 +1,137 @@
+use result::{Error, Result};
+
+use {AnyObject, Hash, Object, Symbol, VerifiedObject};
+
+/// Keyword arguments of a method
+///
+/// Ruby passes keyword arguments to C functions as a trailing `Hash`. `KeywordArguments` checks
+/// that the hash contains only the expected keywords and converts its values to the required
+/// types.
+///
+/// See `VM::parse_keyword_arguments()`.
+#[derive(Debug)]
+pub struct KeywordArguments {
+    hash: Hash,
+}
+
+impl KeywordArguments {
+    /// Extracts keyword arguments from the arguments of a method.
+    ///
+    /// If the last argument is a `Hash`, it is used as keyword arguments. Otherwise no keyword
+    /// arguments are given.
+    ///
+    /// Returns `ArgumentError` if the hash contains keywords which are not listed in `keywords`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Fixnum, Hash, KeywordArguments, Object, RString, Symbol, VM};
+    /// # VM::init();
+    ///
+    /// let mut options = Hash::new();
+    /// options.store(Symbol::new("hots"), RString::new("example.com"));
+    ///
+    /// let arguments = [Fixnum::new(1).to_any_object(), options.to_any_object()];
+    ///
+    /// let error = KeywordArguments::parse(&arguments, &["host", "port"]).unwrap_err();
+    ///
+    /// assert_eq!(error.to_string(), "unknown keyword: :hots");
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// def start(number, host: 'localhost', port: 80); end
+    ///
+    /// start(1, hots: 'example.com') # ArgumentError: unknown keyword: :hots
+    /// ```
+    pub fn parse(arguments: &[AnyObject], keywords: &[&str]) -> Result<Self> {
+        let hash = arguments
+            .last()
+            .and_then(|argument| argument.try_convert_to::<Hash>().ok())
+            .unwrap_or_else(Hash::new);
+
+        let mut unknown_keywords = Vec::new();
+
+        hash.each(|key, _| {
+            let is_known = key.try_convert_to::<Symbol>()
+                .map(|key| keywords.contains(&key.to_str()))
+                .unwrap_or(false);
+
+            if !is_known {
+                unknown_keywords.push(key.inspect());
+            }
+        });
+
+        match unknown_keywords.len() {
+            0 => Ok(KeywordArguments { hash: hash }),
+            1 => Err(Error::ArgumentError(format!("unknown keyword: {}", unknown_keywords[0]))),
+            _ => {
+                let message = format!("unknown keywords: {}", unknown_keywords.join(", "));
+
+                Err(Error::ArgumentError(message))
+            }
+        }
+    }
+
+    /// Retrieves a keyword argument and converts it to the given type.
+    ///
+    /// Returns `Ok(None)` if the argument is not given or is `nil`, and `TypeError` if it has
+    /// a wrong type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Fixnum, Hash, Object, RString, Symbol, VM};
+    /// # VM::init();
+    ///
+    /// let mut options = Hash::new();
+    /// options.store(Symbol::new("port"), RString::new("8080"));
+    ///
+    /// let arguments = [options.to_any_object()];
+    /// let keywords = VM::parse_keyword_arguments(&arguments, &["host", "port"]).unwrap();
+    ///
+    /// assert_eq!(keywords.get::<RString>("host"), Ok(None));
+    /// assert!(keywords.get::<Fixnum>("port").is_err());
+    /// ```
+    pub fn get<T: VerifiedObject>(&self, keyword: &str) -> Result<Option<T>> {
+        let value = self.hash.at(&Symbol::new(keyword));
+
+        if value.is_nil() {
+            return Ok(None);
+        }
+
+        value
+            .try_convert_to::<T>()
+            .map(Some)
+            .map_err(|error| Error::TypeError(format!("{} for keyword '{}'", error, keyword)))
+    }
+
+    /// Retrieves a keyword argument, returns `default` if the argument is not given.
+    ///
+    /// See the example for `VM::parse_keyword_arguments()`.
+    pub fn get_or<T: VerifiedObject>(&self, keyword: &str, default: T) -> Result<T> {
+        self.get(keyword).map(|value| value.unwrap_or(default))
+    }
+
+    /// Retrieves a required keyword argument.
+    ///
+    /// Returns `ArgumentError` if the argument is not given.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{RString, VM};
+    /// # VM::init();
+    ///
+    /// let keywords = VM::parse_keyword_arguments(&[], &["host"]).unwrap();
+    /// let error = keywords.get_required::<RString>("host").unwrap_err();
+    ///
+    /// assert_eq!(error.to_string(), "missing keyword: :host");
+    /// ```
+    pub fn get_required<T: VerifiedObject>(&self, keyword: &str) -> Result<T> {
+        self.get(keyword).and_then(|value| {
+            value.ok_or_else(|| Error::ArgumentError(format!("missing keyword: :{}", keyword)))
+        })
+    }
+}
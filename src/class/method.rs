@@ -0,0 +1,176 @@
+use std::convert::{From, TryFrom};
+use std::fmt;
+
+use binding::method;
+use result::Error;
+use types::{Value, ValueType};
+use util;
+
+use {AnyObject, Class, Object, Proc, UnboundMethod, VerifiedObject};
+
+/// `Method`
+///
+/// A method bound to its receiver. It can be retrieved using `Object::method()`.
+#[derive(PartialEq)]
+pub struct Method {
+    value: Value,
+}
+
+impl Method {
+    /// Calls the method with given arguments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let string = RString::new("Hello, World!");
+    /// let method = string.method("sub");
+    ///
+    /// let arguments = [
+    ///     RString::new("World").to_any_object(),
+    ///     RString::new("Ruby").to_any_object(),
+    /// ];
+    ///
+    /// let result = method.call(&arguments).try_convert_to::<RString>();
+    ///
+    /// assert_eq!(result.unwrap().to_str(), "Hello, Ruby!");
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// method = 'Hello, World!'.method(:sub)
+    ///
+    /// method.call('World', 'Ruby') == 'Hello, Ruby!'
+    /// ```
+    pub fn call(&self, arguments: &[AnyObject]) -> AnyObject {
+        let arguments = util::arguments_to_values(Some(arguments));
+        let result = method::call(self.value(), arguments);
+
+        AnyObject::from(result)
+    }
+
+    /// Returns the number of mandatory arguments of the method.
+    ///
+    /// For methods with optional arguments, returns `-n - 1` where `n` is the number of
+    /// mandatory arguments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Array, Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// assert_eq!(RString::new("").method("length").arity(), 0);
+    /// assert_eq!(RString::new("").method("start_with?").arity(), -1);
+    /// assert_eq!(Array::new().method("fill").arity(), -1);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// ''.method(:length).arity == 0
+    /// ''.method(:start_with?).arity == -1
+    /// [].method(:fill).arity == -1
+    /// ```
+    pub fn arity(&self) -> i64 {
+        method::arity(self.value())
+    }
+
+    /// Converts the method to a lambda `Proc`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Fixnum, Object, VM};
+    /// # VM::init();
+    ///
+    /// let rproc = Fixnum::new(2).method("+").to_proc();
+    ///
+    /// let result = rproc.call(Some(&[Fixnum::new(3).to_any_object()]));
+    ///
+    /// assert_eq!(result.try_convert_to::<Fixnum>(), Ok(Fixnum::new(5)));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// rproc = 2.method(:+).to_proc
+    ///
+    /// rproc.call(3) == 5
+    /// ```
+    pub fn to_proc(&self) -> Proc {
+        Proc::from(method::to_proc(self.value()))
+    }
+
+    /// Dissociates the method from its receiver.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Fixnum, Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let length = RString::new("Hello").method("length").unbind();
+    ///
+    /// let result = length.bind(RString::new("Hi").to_any_object()).call(&[]);
+    ///
+    /// assert_eq!(result.try_convert_to::<Fixnum>(), Ok(Fixnum::new(2)));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// length = 'Hello'.method(:length).unbind
+    ///
+    /// length.bind('Hi').call == 2
+    /// ```
+    pub fn unbind(&self) -> UnboundMethod {
+        UnboundMethod::from(method::unbind(self.value()))
+    }
+}
+
+impl From<Value> for Method {
+    fn from(value: Value) -> Self {
+        Method { value: value }
+    }
+}
+
+impl Object for Method {
+    #[inline]
+    fn value(&self) -> Value {
+        self.value
+    }
+}
+
+impl fmt::Debug for Method {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.inspect())
+    }
+}
+
+impl VerifiedObject for Method {
+    fn is_correct_type<T: Object>(object: &T) -> bool {
+        object.value().ty() == ValueType::Data && object.class() == Class::from_existing("Method")
+    }
+
+    fn error_message() -> &'static str {
+        "Error converting to Method"
+    }
+}
+
+impl TryFrom<AnyObject> for Method {
+    type Error = Error;
+
+    fn try_from(object: AnyObject) -> Result<Self, Error> {
+        object.try_convert_to::<Method>()
+    }
+}
+
+impl From<Method> for AnyObject {
+    fn from(object: Method) -> Self {
+        object.to_any_object()
+    }
+}
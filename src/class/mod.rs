@@ -2,15 +2,27 @@ pub mod any_object;
 pub mod array;
 pub mod boolean;
 pub mod class;
+pub mod class_builder;
+pub mod encoding;
+pub mod enumerator;
+pub mod fiber;
 pub mod fixnum;
 pub mod float;
 pub mod gc;
 pub mod hash;
 pub mod integer;
+pub mod keyword_arguments;
+pub mod method;
+pub mod module;
+pub mod mutex;
 pub mod nil_class;
+pub mod object_space;
+pub mod queue;
 pub mod rproc;
 pub mod string;
 pub mod traits;
+pub mod unbound_method;
 pub mod thread;
 pub mod symbol;
 pub mod vm;
+pub mod weak_ref;
@@ -0,0 +1,217 @@
+use std::convert::{From, TryFrom};
+use std::fmt;
+
+use binding::global::rb_cObject;
+use binding::{class, module, vm};
+use binding::util as binding_util;
+use result::Error;
+use types::{Value, ValueType};
+use util;
+
+use {AnyObject, Object, VerifiedObject, VM};
+
+/// `Module`
+///
+/// Methods are defined with `def`, `def_self` and `define` from `Object` trait, the same way as
+/// for `Class`.
+#[derive(PartialEq)]
+pub struct Module {
+    value: Value,
+}
+
+impl Module {
+    /// Creates a new `Module` and assigns it to a top-level constant.
+    ///
+    /// If the module already exists, it is reopened. Raises `TypeError` if the constant is
+    /// defined, but it is not a module.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Module, VM};
+    /// # VM::init();
+    ///
+    /// let module = Module::new("Greeter");
+    ///
+    /// assert_eq!(module, Module::from_existing("Greeter"));
+    /// assert_eq!(Module::new("Greeter"), module);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// module Greeter
+    /// end
+    /// ```
+    pub fn new(name: &str) -> Self {
+        Self::from(module::define_module(name))
+    }
+
+    /// Retrieves an existing `Module` object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Module, VM};
+    /// # VM::init();
+    ///
+    /// let module = Module::from_existing("Comparable");
+    ///
+    /// assert_eq!(module, Module::from_existing("Comparable"));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Comparable
+    /// ```
+    pub fn from_existing(name: &str) -> Self {
+        let object_class = unsafe { rb_cObject };
+
+        Self::from(binding_util::get_constant(name, object_class))
+    }
+
+    /// Retrieves a constant from module.
+    ///
+    /// Returns `Error::ArgumentError` if `name` is not a valid constant name and
+    /// `Error::Exception` with `NameError` if the constant is not defined.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Float, Module, VM};
+    /// # VM::init();
+    ///
+    /// let pi = Module::from_existing("Math")
+    ///     .const_get("PI")
+    ///     .unwrap()
+    ///     .try_convert_to::<Float>()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(pi.to_f64(), ::std::f64::consts::PI);
+    /// assert!(Module::from_existing("Math").const_get("TAU").is_err());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Math::PI
+    /// ```
+    pub fn const_get(&self, name: &str) -> Result<AnyObject, Error> {
+        util::check_constant_name(name)?;
+
+        let module = self.value();
+
+        vm::protect_value(|| class::const_get(module, name))
+            .map(AnyObject::from)
+            .map_err(|exception| Error::from_exception(AnyObject::from(exception)))
+    }
+
+    /// Defines a constant for module.
+    ///
+    /// Raises `ArgumentError` if `name` is not a valid constant name (it must start with an
+    /// uppercase letter).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Class, Module, Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// Module::new("MyGem").const_set("VERSION", &RString::new("1.0.0"));
+    ///
+    /// let code = RString::new("MyGem::VERSION");
+    ///
+    /// let version = Class::from_existing("Object")
+    ///     .protect_send("eval", &[code.to_any_object()])
+    ///     .unwrap()
+    ///     .try_convert_to::<RString>()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(version.to_str(), "1.0.0");
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// module MyGem
+    ///   VERSION = '1.0.0'
+    /// end
+    /// ```
+    pub fn const_set<T: Object>(&mut self, name: &str, value: &T) {
+        if let Err(error) = util::check_constant_name(name) {
+            VM::raise_error(error);
+        }
+
+        class::const_set(self.value(), name, value.value());
+    }
+
+    /// Checks if a constant is defined in module.
+    ///
+    /// Returns `false` if `name` is not a valid constant name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Module, VM};
+    /// # VM::init();
+    ///
+    /// let math = Module::from_existing("Math");
+    ///
+    /// assert!(math.const_defined("PI"));
+    /// assert!(!math.const_defined("TAU"));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Math.const_defined?(:PI) == true
+    /// Math.const_defined?(:TAU) == false
+    /// ```
+    pub fn const_defined(&self, name: &str) -> bool {
+        util::check_constant_name(name).is_ok() && class::const_defined(self.value(), name)
+    }
+}
+
+impl From<Value> for Module {
+    fn from(value: Value) -> Self {
+        Module { value: value }
+    }
+}
+
+impl Object for Module {
+    #[inline]
+    fn value(&self) -> Value {
+        self.value
+    }
+}
+
+impl fmt::Debug for Module {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.inspect())
+    }
+}
+
+impl VerifiedObject for Module {
+    fn is_correct_type<T: Object>(object: &T) -> bool {
+        object.value().ty() == ValueType::Module
+    }
+
+    fn error_message() -> &'static str {
+        "Error converting to Module"
+    }
+}
+
+impl TryFrom<AnyObject> for Module {
+    type Error = Error;
+
+    fn try_from(object: AnyObject) -> Result<Self, Error> {
+        object.try_convert_to::<Module>()
+    }
+}
+
+impl From<Module> for AnyObject {
+    fn from(object: Module) -> Self {
+        object.to_any_object()
+    }
+}
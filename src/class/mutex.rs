@@ -0,0 +1,248 @@
+use std::convert::{From, TryFrom};
+use std::fmt;
+
+use binding::mutex;
+use result::Error;
+use types::{Value, ValueType};
+
+use {AnyObject, Class, Object, VerifiedObject};
+
+/// `Mutex`
+///
+/// Ruby threads share the GVL, but a thread can still be switched in the middle of a sequence
+/// of calls to Ruby. `Mutex` protects a shared resource from being accessed by several Ruby
+/// threads at once.
+///
+/// # Examples
+///
+/// ```
+/// use ruru::{AnyObject, Array, Class, Fixnum, Mutex, NilClass, Object, Thread, VM};
+/// # VM::init();
+///
+/// let counter = Array::new().push(Fixnum::new(0)).to_any_object();
+/// let mutex = Mutex::new().to_any_object();
+///
+/// let threads: Vec<AnyObject> = (0..2)
+///     .map(|_| {
+///         let counter = counter.clone();
+///         let mutex = mutex.clone();
+///
+///         Thread::new(move || {
+///             let mutex = mutex.try_convert_to::<Mutex>().unwrap();
+///             let mut counter = counter.try_convert_to::<Array>().unwrap();
+///
+///             for _ in 0..100 {
+///                 mutex.synchronize(|| {
+///                     let value = counter.at(0).try_convert_to::<Fixnum>().unwrap().to_i64();
+///
+///                     // Let the other thread run between reading and writing the counter
+///                     Class::from_existing("Thread").protect_send("pass", &[]).unwrap();
+///
+///                     counter.store(0, Fixnum::new(value + 1))
+///                 });
+///             }
+///
+///             NilClass::new()
+///         }).to_any_object()
+///     })
+///     .collect();
+///
+/// for thread in threads {
+///     thread.protect_send("join", &[]).unwrap();
+/// }
+///
+/// let counter = counter.try_convert_to::<Array>().unwrap();
+///
+/// assert_eq!(counter.at(0).try_convert_to::<Fixnum>(), Ok(Fixnum::new(200)));
+/// ```
+///
+/// Ruby:
+///
+/// ```ruby
+/// counter = [0]
+/// mutex = Mutex.new
+///
+/// threads = 2.times.map do
+///   Thread.new do
+///     100.times do
+///       mutex.synchronize do
+///         value = counter[0]
+///
+///         Thread.pass
+///
+///         counter[0] = value + 1
+///       end
+///     end
+///   end
+/// end
+///
+/// threads.each(&:join)
+///
+/// counter[0] == 200
+/// ```
+#[derive(PartialEq)]
+pub struct Mutex {
+    value: Value,
+}
+
+impl Mutex {
+    /// Creates a new unlocked `Mutex`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Mutex, VM};
+    /// # VM::init();
+    ///
+    /// let mutex = Mutex::new();
+    ///
+    /// assert!(!mutex.is_locked());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// mutex = Mutex.new
+    ///
+    /// mutex.locked? == false
+    /// ```
+    pub fn new() -> Self {
+        Self::from(mutex::new())
+    }
+
+    /// Locks the mutex. Blocks the current Ruby thread until the mutex is available.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Mutex, VM};
+    /// # VM::init();
+    ///
+    /// let mutex = Mutex::new();
+    ///
+    /// mutex.lock();
+    /// assert!(mutex.is_locked());
+    ///
+    /// mutex.unlock();
+    /// assert!(!mutex.is_locked());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// mutex = Mutex.new
+    ///
+    /// mutex.lock
+    /// mutex.locked? == true
+    ///
+    /// mutex.unlock
+    /// mutex.locked? == false
+    /// ```
+    pub fn lock(&self) {
+        mutex::lock(self.value());
+    }
+
+    /// Unlocks the mutex.
+    ///
+    /// Raises `ThreadError` if the mutex is not locked by the current thread.
+    ///
+    /// See the example for `lock()`.
+    pub fn unlock(&self) {
+        mutex::unlock(self.value());
+    }
+
+    /// Checks whether the mutex is locked by any thread.
+    ///
+    /// See the example for `lock()`.
+    pub fn is_locked(&self) -> bool {
+        mutex::is_locked(self.value())
+    }
+
+    /// Runs the closure while holding the lock.
+    ///
+    /// The mutex is unlocked when the closure finishes, even if it raises an exception or panics
+    /// (Ruby `Mutex#synchronize`).
+    ///
+    /// See the example for `Mutex` for sharing a resource between threads.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::panic;
+    ///
+    /// use ruru::{Mutex, NilClass, VM};
+    /// # VM::init();
+    ///
+    /// let mutex = Mutex::new();
+    ///
+    /// let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+    ///     mutex.synchronize(|| -> NilClass { panic!("Something went wrong") })
+    /// }));
+    ///
+    /// assert!(result.is_err());
+    /// assert!(!mutex.is_locked());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// mutex = Mutex.new
+    ///
+    /// begin
+    ///   mutex.synchronize { raise 'Something went wrong' }
+    /// rescue RuntimeError
+    /// end
+    ///
+    /// mutex.locked? == false
+    /// ```
+    pub fn synchronize<F, R>(&self, func: F) -> R
+    where
+        F: FnOnce() -> R,
+        R: Object,
+    {
+        R::from(mutex::synchronize(self.value(), || func().value()))
+    }
+}
+
+impl From<Value> for Mutex {
+    fn from(value: Value) -> Self {
+        Mutex { value: value }
+    }
+}
+
+impl Object for Mutex {
+    #[inline]
+    fn value(&self) -> Value {
+        self.value
+    }
+}
+
+impl fmt::Debug for Mutex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.inspect())
+    }
+}
+
+impl VerifiedObject for Mutex {
+    fn is_correct_type<T: Object>(object: &T) -> bool {
+        object.value().ty() == ValueType::Data && object.class() == Class::from_existing("Mutex")
+    }
+
+    fn error_message() -> &'static str {
+        "Error converting to Mutex"
+    }
+}
+
+impl TryFrom<AnyObject> for Mutex {
+    type Error = Error;
+
+    fn try_from(object: AnyObject) -> Result<Self, Error> {
+        object.try_convert_to::<Mutex>()
+    }
+}
+
+impl From<Mutex> for AnyObject {
+    fn from(object: Mutex) -> Self {
+        object.to_any_object()
+    }
+}
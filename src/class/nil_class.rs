@@ -1,13 +1,15 @@
-use std::convert::From;
+use std::convert::{From, TryFrom};
 use std::default::Default;
+use std::fmt;
 
 use binding::global::RubySpecialConsts;
+use result::Error;
 use types::{InternalValue, Value, ValueType};
 
-use {Object, VerifiedObject};
+use {AnyObject, Object, VerifiedObject};
 
 /// `NilClass`
-#[derive(Debug, PartialEq)]
+#[derive(PartialEq)]
 pub struct NilClass {
     value: Value,
 }
@@ -53,6 +55,12 @@ impl Object for NilClass {
     }
 }
 
+impl fmt::Debug for NilClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.inspect())
+    }
+}
+
 impl VerifiedObject for NilClass {
     fn is_correct_type<T: Object>(object: &T) -> bool {
         object.value().ty() == ValueType::Nil
@@ -62,3 +70,17 @@ impl VerifiedObject for NilClass {
         "Error converting to NilClass"
     }
 }
+
+impl TryFrom<AnyObject> for NilClass {
+    type Error = Error;
+
+    fn try_from(object: AnyObject) -> Result<Self, Error> {
+        object.try_convert_to::<NilClass>()
+    }
+}
+
+impl From<NilClass> for AnyObject {
+    fn from(object: NilClass) -> Self {
+        object.to_any_object()
+    }
+}
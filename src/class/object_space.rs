@@ -0,0 +1,59 @@
+use binding::object_space;
+
+use {AnyObject, Array, Class, Object};
+
+/// Inspection of the objects living in the Ruby heap
+pub struct ObjectSpace;
+
+impl ObjectSpace {
+    /// Returns an iterator over all live objects or over instances of the given class
+    /// (including instances of its subclasses).
+    ///
+    /// The objects are collected before the iteration starts, so the iterator keeps them from
+    /// being garbage collected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Array, Class, Object, ObjectSpace, RString, VM};
+    /// # VM::init();
+    ///
+    /// let string_class = Class::from_existing("String");
+    ///
+    /// VM::gc_disable();
+    ///
+    /// let before = ObjectSpace::each_object(Some(&string_class)).count();
+    ///
+    /// let strings: Array = (0..10)
+    ///     .map(|number| RString::new(&number.to_string()).to_any_object())
+    ///     .collect();
+    ///
+    /// let after = ObjectSpace::each_object(Some(&string_class)).count();
+    ///
+    /// VM::gc_enable();
+    ///
+    /// assert_eq!(strings.length(), 10);
+    /// assert!(after >= before + 10);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// GC.disable
+    ///
+    /// before = ObjectSpace.each_object(String).count
+    ///
+    /// strings = (0...10).map(&:to_s)
+    ///
+    /// after = ObjectSpace.each_object(String).count
+    ///
+    /// GC.enable
+    ///
+    /// after >= before + 10
+    /// ```
+    pub fn each_object(klass: Option<&Class>) -> impl Iterator<Item = AnyObject> {
+        let objects = object_space::each_object(klass.map(|klass| klass.value()));
+
+        Array::from(objects).into_iter()
+    }
+}
@@ -0,0 +1,220 @@
+use std::convert::{From, TryFrom};
+use std::fmt;
+
+use binding::queue;
+use result::Error;
+use types::{Value, ValueType};
+
+use {AnyObject, Class, Object, VerifiedObject};
+
+/// `Queue`
+///
+/// A thread-safe FIFO queue which can be used to pass objects between Ruby threads.
+///
+/// # Examples
+///
+/// ```
+/// use ruru::{Fixnum, NilClass, Object, Queue, Thread, VM};
+/// # VM::init();
+///
+/// let mut queue = Queue::new();
+/// let producer_queue = queue.to_any_object();
+///
+/// let producer = Thread::new(move || {
+///     let mut queue = producer_queue.try_convert_to::<Queue>().unwrap();
+///
+///     for number in 1..4 {
+///         queue.push(Fixnum::new(number));
+///     }
+///
+///     NilClass::new()
+/// });
+///
+/// let numbers: Vec<i64> = (0..3)
+///     .map(|_| queue.pop(false).try_convert_to::<Fixnum>().unwrap().to_i64())
+///     .collect();
+///
+/// producer.protect_send("join", &[]).unwrap();
+///
+/// assert_eq!(numbers, vec![1, 2, 3]);
+/// assert!(queue.is_empty());
+/// ```
+///
+/// Ruby:
+///
+/// ```ruby
+/// queue = Queue.new
+///
+/// producer = Thread.new do
+///   (1..3).each { |number| queue.push(number) }
+/// end
+///
+/// numbers = 3.times.map { queue.pop }
+///
+/// producer.join
+///
+/// numbers == [1, 2, 3]
+/// queue.empty? == true
+/// ```
+#[derive(PartialEq)]
+pub struct Queue {
+    value: Value,
+}
+
+impl Queue {
+    /// Creates a new empty `Queue`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Queue, VM};
+    /// # VM::init();
+    ///
+    /// let queue = Queue::new();
+    ///
+    /// assert_eq!(queue.size(), 0);
+    /// assert!(queue.is_empty());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// queue = Queue.new
+    ///
+    /// queue.size == 0
+    /// queue.empty? == true
+    /// ```
+    pub fn new() -> Self {
+        Self::from(queue::new())
+    }
+
+    /// Pushes an object to the end of the queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Fixnum, Queue, VM};
+    /// # VM::init();
+    ///
+    /// let mut queue = Queue::new();
+    ///
+    /// queue.push(Fixnum::new(1)).push(Fixnum::new(2));
+    ///
+    /// assert_eq!(queue.size(), 2);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// queue = Queue.new
+    ///
+    /// queue.push(1).push(2)
+    ///
+    /// queue.size == 2
+    /// ```
+    pub fn push<T: Object>(&mut self, item: T) -> &mut Self {
+        queue::push(self.value(), item.value());
+
+        self
+    }
+
+    /// Retrieves an object from the beginning of the queue.
+    ///
+    /// If the queue is empty and `non_block` is `false`, the current Ruby thread is suspended
+    /// until an object is pushed to the queue. If `non_block` is `true`, `ThreadError` is raised
+    /// instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Fixnum, Object, Queue, VM};
+    /// # VM::init();
+    ///
+    /// let mut queue = Queue::new();
+    ///
+    /// queue.push(Fixnum::new(1));
+    ///
+    /// assert_eq!(queue.pop(true).try_convert_to::<Fixnum>(), Ok(Fixnum::new(1)));
+    ///
+    /// let result = VM::protect(|| {
+    ///     queue.pop(true);
+    /// });
+    ///
+    /// assert!(result.is_err());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// queue = Queue.new
+    ///
+    /// queue.push(1)
+    ///
+    /// queue.pop(true) == 1
+    ///
+    /// begin
+    ///   queue.pop(true)
+    /// rescue ThreadError
+    /// end
+    /// ```
+    pub fn pop(&mut self, non_block: bool) -> AnyObject {
+        AnyObject::from(queue::pop(self.value(), non_block))
+    }
+
+    /// Returns the number of objects in the queue.
+    ///
+    /// See the example for `push()`.
+    pub fn size(&self) -> usize {
+        queue::size(self.value())
+    }
+
+    /// Checks whether the queue is empty.
+    ///
+    /// See the example for `new()`.
+    pub fn is_empty(&self) -> bool {
+        queue::is_empty(self.value())
+    }
+}
+
+impl From<Value> for Queue {
+    fn from(value: Value) -> Self {
+        Queue { value: value }
+    }
+}
+
+impl Object for Queue {
+    #[inline]
+    fn value(&self) -> Value {
+        self.value
+    }
+}
+
+impl fmt::Debug for Queue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.inspect())
+    }
+}
+
+impl VerifiedObject for Queue {
+    fn is_correct_type<T: Object>(object: &T) -> bool {
+        object.value().ty() == ValueType::Data && object.class() == Class::from_existing("Queue")
+    }
+
+    fn error_message() -> &'static str {
+        "Error converting to Queue"
+    }
+}
+
+impl TryFrom<AnyObject> for Queue {
+    type Error = Error;
+
+    fn try_from(object: AnyObject) -> Result<Self, Error> {
+        object.try_convert_to::<Queue>()
+    }
+}
+
+impl From<Queue> for AnyObject {
+    fn from(object: Queue) -> Self {
+        object.to_any_object()
+    }
+}
@@ -1,18 +1,85 @@
-use std::convert::From;
+use std::convert::{From, TryFrom};
+use std::fmt;
 
-use binding::rproc;
-use types::Value;
+use binding::{closure, rproc};
+use result::Error;
+use types::{Value, ValueType};
 use util;
 
 use {AnyObject, Class, Object, VerifiedObject};
 
 /// `Proc` (works with `Lambda` as well)
-#[derive(Debug, PartialEq)]
+#[derive(PartialEq)]
 pub struct Proc {
     value: Value,
 }
 
 impl Proc {
+    /// Creates a new lambda `Proc` which calls a Rust closure.
+    ///
+    /// The closure receives the arguments of the proc as a slice of `AnyObject`s. It is freed
+    /// when the `Proc` is garbage collected.
+    ///
+    /// A panic in the closure is converted to `RuntimeError` with the panic message, the same as
+    /// in methods defined with `methods!`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{AnyObject, Class, Fixnum, Object, Proc, RString, VM};
+    /// # VM::init();
+    ///
+    /// let offset = 10;
+    ///
+    /// let rproc = Proc::new_from_closure(move |arguments: &[AnyObject]| {
+    ///     let sum: i64 = arguments
+    ///         .iter()
+    ///         .map(|argument| argument.try_convert_to::<Fixnum>().unwrap().to_i64())
+    ///         .sum();
+    ///
+    ///     Fixnum::new(sum + offset).to_any_object()
+    /// });
+    ///
+    /// let code = RString::new("lambda { |block| block.call(1, 2) }").to_any_object();
+    /// let call_with_one_and_two = Class::from_existing("Kernel")
+    ///     .protect_send("eval", &[code])
+    ///     .unwrap()
+    ///     .try_convert_to::<Proc>()
+    ///     .unwrap();
+    ///
+    /// let result = call_with_one_and_two.call(Some(&[rproc.to_any_object()]));
+    ///
+    /// assert_eq!(result.try_convert_to::<Fixnum>(), Ok(Fixnum::new(13)));
+    ///
+    /// let failing = Proc::new_from_closure(|_arguments: &[AnyObject]| panic!("Invalid input"));
+    /// let error = failing.protect_send("call", &[]).unwrap_err();
+    ///
+    /// assert_eq!(error.to_exception(), Class::from_existing("RuntimeError"));
+    /// assert_eq!(error.to_string(), "Invalid input");
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// offset = 10
+    ///
+    /// rproc = lambda { |*arguments| arguments.sum + offset }
+    ///
+    /// call_with_one_and_two = lambda { |block| block.call(1, 2) }
+    ///
+    /// call_with_one_and_two.call(rproc) == 13
+    ///
+    /// failing = lambda { |*arguments| raise 'Invalid input' }
+    /// ```
+    pub fn new_from_closure<F>(closure: F) -> Self
+    where
+        F: Fn(&[AnyObject]) -> AnyObject + 'static,
+    {
+        let closure = closure::wrap(Box::new(closure));
+
+        Self::from(closure::to_proc(closure))
+    }
+
     /// Calls a proc with given arguments
     ///
     /// # Examples
@@ -78,12 +145,32 @@ impl Object for Proc {
     }
 }
 
+impl fmt::Debug for Proc {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.inspect())
+    }
+}
+
 impl VerifiedObject for Proc {
     fn is_correct_type<T: Object>(object: &T) -> bool {
-        object.class() == Class::from_existing("Proc")
+        object.value().ty() == ValueType::Data && object.class() == Class::from_existing("Proc")
     }
 
     fn error_message() -> &'static str {
         "Error converting to Proc"
     }
 }
+
+impl TryFrom<AnyObject> for Proc {
+    type Error = Error;
+
+    fn try_from(object: AnyObject) -> Result<Self, Error> {
+        object.try_convert_to::<Proc>()
+    }
+}
+
+impl From<Proc> for AnyObject {
+    fn from(object: Proc) -> Self {
+        object.to_any_object()
+    }
+}
@@ -1,12 +1,14 @@
-use std::convert::From;
+use std::cmp::Ordering;
+use std::convert::{From, TryFrom};
+use std::fmt;
 
 use binding::string;
+use result::Error;
 use types::{Value, ValueType};
 
-use {Object, VerifiedObject};
+use {AnyObject, Encoding, Object, VerifiedObject};
 
 /// `String`
-#[derive(Debug, PartialEq)]
 pub struct RString {
     value: Value,
 }
@@ -61,6 +63,138 @@ impl RString {
         Self::from(string::new_utf8(string))
     }
 
+    /// Creates a new frozen instance of Ruby `String` with UTF8 encoding (Ruby `String#-@`).
+    ///
+    /// Frozen strings are deduplicated, so strings with the same contents share the same object
+    /// and are not allocated repeatedly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let string = RString::new_frozen("Hello, World!");
+    /// let same_string = RString::new_frozen("Hello, World!");
+    ///
+    /// assert!(string.is_frozen());
+    /// assert_eq!(string.to_str(), "Hello, World!");
+    ///
+    /// assert_eq!(string.object_id(), same_string.object_id());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// string = -'Hello, World!'
+    /// same_string = -'Hello, World!'
+    ///
+    /// string.frozen? == true
+    /// string.object_id == same_string.object_id
+    /// ```
+    pub fn new_frozen(string: &str) -> Self {
+        Self::from(string::new_frozen(string))
+    }
+
+    /// Creates a new instance of Ruby `String` containing given `string` with given `encoding`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Encoding, Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let shift_jis = Encoding::find("Shift_JIS").unwrap();
+    /// let string = RString::new_with_encoding("Hello", &shift_jis);
+    ///
+    /// let encoding = string.protect_send("encoding", &[]).unwrap().try_convert_to::<Encoding>();
+    ///
+    /// assert_eq!(encoding, Ok(shift_jis));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// str = 'Hello'.force_encoding(Encoding::Shift_JIS)
+    ///
+    /// str.encoding == Encoding::Shift_JIS
+    /// ```
+    pub fn new_with_encoding(string: &str, encoding: &Encoding) -> Self {
+        let value = string::new(string);
+
+        Self::from(string::force_encoding(value, encoding.value()))
+    }
+
+    /// Creates a new instance of Ruby `String` containing given `bytes`.
+    ///
+    /// The bytes are not required to be a valid UTF-8 sequence. The encoding of the string is
+    /// set to `ASCII-8BIT` (also known as `BINARY`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let bytes = [0, 159, 146, 150];
+    /// let string = RString::from_bytes(&bytes);
+    ///
+    /// let encoding = string
+    ///     .protect_send("encoding", &[])
+    ///     .unwrap()
+    ///     .protect_send("to_s", &[])
+    ///     .unwrap();
+    ///
+    /// assert_eq!(string.to_bytes(), &bytes);
+    /// assert_eq!(encoding.try_convert_to::<RString>().unwrap().to_str(), "ASCII-8BIT");
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// string = [0, 159, 146, 150].pack('C*')
+    ///
+    /// string.encoding.to_s == 'ASCII-8BIT'
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        Self::from(string::new_from_bytes(bytes))
+    }
+
+    /// Creates a new instance of Ruby `String` by formatting `arguments` according to `template`
+    /// (Ruby `Kernel#sprintf`).
+    ///
+    /// All Ruby format specifiers are supported. `ArgumentError` is raised if there are not enough
+    /// arguments for the template.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Fixnum, Float, Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let string = RString::format("%05d", &[Fixnum::new(42).to_any_object()]);
+    ///
+    /// assert_eq!(string.to_str(), "00042");
+    ///
+    /// let arguments = [RString::new("pi").to_any_object(), Float::new(3.14159).to_any_object()];
+    /// let string = RString::format("%s = %.2f", &arguments);
+    ///
+    /// assert_eq!(string.to_str(), "pi = 3.14");
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// sprintf('%05d', 42) == '00042'
+    ///
+    /// sprintf('%s = %.2f', 'pi', 3.14159) == 'pi = 3.14'
+    /// ```
+    pub fn format(template: &str, arguments: &[AnyObject]) -> Self {
+        let arguments = arguments.iter().map(Object::value).collect();
+
+        Self::from(string::format(template, arguments))
+    }
+
     /// Retrieves underlying Rust `String` from Ruby `String` object.
     ///
     /// # Examples
@@ -169,6 +303,235 @@ impl RString {
         string::value_to_str_unchecked(value)
     }
 
+    /// Retrieves the bytes of Ruby `String` object.
+    ///
+    /// The slice points directly to the internal buffer of the Ruby string, no data is copied.
+    ///
+    /// # Safety
+    ///
+    /// Although the function is safe to call, the slice is only valid while the Ruby string is
+    /// neither modified nor garbage collected. Do not keep the slice after passing control back
+    /// to Ruby, copy the data with `to_vec()` instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{RString, VM};
+    /// # VM::init();
+    ///
+    /// let string = RString::new("Hello,\0World!");
+    ///
+    /// assert_eq!(string.to_bytes(), b"Hello,\0World!");
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// str = "Hello,\0World!"
+    ///
+    /// str.bytes == [72, 101, 108, 108, 111, 44, 0, 87, 111, 114, 108, 100, 33]
+    /// ```
+    pub fn to_bytes(&self) -> &[u8] {
+        string::value_to_bytes_unchecked(self.value())
+    }
+
+    /// Returns an iterator over the characters of Ruby `String` object.
+    ///
+    /// Null-bytes inside of the string are returned as `'\0'` characters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the string is not a valid UTF-8 sequence. Use `try_chars()` to handle this case
+    /// without panicking.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{RString, VM};
+    /// # VM::init();
+    ///
+    /// let string = RString::new_utf8("Héllo");
+    ///
+    /// let chars: Vec<char> = string.chars().collect();
+    ///
+    /// assert_eq!(chars, vec!['H', 'é', 'l', 'l', 'o']);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// str = 'Héllo'
+    ///
+    /// str.chars == ['H', 'é', 'l', 'l', 'o']
+    /// ```
+    pub fn chars<'a>(&'a self) -> impl Iterator<Item = char> + 'a {
+        match self.try_chars() {
+            Ok(chars) => chars,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Returns an iterator over the characters of Ruby `String` object or an `ArgumentError`
+    /// if the string is not a valid UTF-8 sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::result::Error;
+    /// use ruru::{RString, VM};
+    /// # VM::init();
+    ///
+    /// let string = RString::new_utf8("Hi!");
+    /// let with_null = RString::from_bytes(&[72, 0, 105]);
+    /// let binary = RString::from_bytes(&[72, 159, 146, 150]);
+    ///
+    /// let chars: Vec<char> = string.try_chars().unwrap().collect();
+    ///
+    /// assert_eq!(chars, vec!['H', 'i', '!']);
+    ///
+    /// let chars: Vec<char> = with_null.try_chars().unwrap().collect();
+    ///
+    /// assert_eq!(chars, vec!['H', '\0', 'i']);
+    ///
+    /// match binary.try_chars() {
+    ///     Err(Error::ArgumentError(_)) => {},
+    ///     _ => panic!("expected ArgumentError"),
+    /// }
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// 'Hi!'.chars == ['H', 'i', '!']
+    /// "H\0i".chars == ['H', "\0", 'i']
+    ///
+    /// [72, 159, 146, 150].pack('C*').force_encoding('UTF-8').valid_encoding? == false
+    /// ```
+    pub fn try_chars<'a>(&'a self) -> Result<impl Iterator<Item = char> + 'a, Error> {
+        let bytes = string::value_to_bytes_unchecked(self.value());
+
+        ::std::str::from_utf8(bytes).map(str::chars).map_err(|error| {
+            Error::ArgumentError(format!("invalid byte sequence in UTF-8: {}", error))
+        })
+    }
+
+    /// Returns the encoding of the string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Encoding, RString, VM};
+    /// # VM::init();
+    ///
+    /// let string = RString::new_utf8("Hello");
+    /// let binary = RString::from_bytes(&[0, 1, 2]);
+    ///
+    /// assert_eq!(string.encoding(), Encoding::utf8());
+    /// assert_eq!(binary.encoding(), Encoding::ascii_8bit());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// 'Hello'.encoding == Encoding::UTF_8
+    /// [0, 1, 2].pack('C*').encoding == Encoding::ASCII_8BIT
+    /// ```
+    pub fn encoding(&self) -> Encoding {
+        Encoding::from(string::encoding(self.value()))
+    }
+
+    /// Changes the encoding of the string to `encoding`.
+    ///
+    /// The string is not transcoded, its bytes stay the same and are only relabeled with the new
+    /// encoding. Use it when the bytes are known to be in the given encoding already.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Encoding, RString, VM};
+    /// # VM::init();
+    ///
+    /// let mut string = RString::from_bytes("Héllo".as_bytes());
+    ///
+    /// assert_eq!(string.encoding().name(), "ASCII-8BIT");
+    ///
+    /// string.force_encoding(&Encoding::utf8());
+    ///
+    /// assert_eq!(string.encoding().name(), "UTF-8");
+    /// assert_eq!(string.to_bytes(), "Héllo".as_bytes());
+    /// assert_eq!(string.to_str(), "Héllo");
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// string = 'Héllo'.b
+    ///
+    /// string.encoding.name == 'ASCII-8BIT'
+    ///
+    /// string.force_encoding(Encoding::UTF_8)
+    ///
+    /// string.encoding.name == 'UTF-8'
+    /// string == 'Héllo'
+    /// ```
+    pub fn force_encoding(&mut self, encoding: &Encoding) -> &mut Self {
+        string::force_encoding(self.value(), encoding.value());
+
+        self
+    }
+
+    /// Transcodes the string to `encoding`.
+    ///
+    /// Returns a new string, the current one is not modified. Returns an error if the string
+    /// contains characters which can not be converted to the target encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Class, Encoding, RString, VM};
+    /// # VM::init();
+    ///
+    /// let utf16 = Encoding::find("UTF-16LE").unwrap();
+    /// let string = RString::new_utf8("Héllo");
+    ///
+    /// let encoded = string.encode(&utf16).unwrap();
+    ///
+    /// assert_eq!(encoded.encoding(), utf16);
+    /// assert_eq!(encoded.bytesize(), 10);
+    ///
+    /// let decoded = encoded.encode(&Encoding::utf8()).unwrap();
+    ///
+    /// assert_eq!(decoded.to_str(), "Héllo");
+    ///
+    /// let ascii = Encoding::find("US-ASCII").unwrap();
+    /// let error = string.encode(&ascii).unwrap_err();
+    ///
+    /// assert_eq!(
+    ///     error.to_exception(),
+    ///     Class::from_existing("Encoding").get_nested_class("UndefinedConversionError")
+    /// );
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// string = 'Héllo'
+    ///
+    /// encoded = string.encode(Encoding::UTF_16LE)
+    ///
+    /// encoded.encoding == Encoding::UTF_16LE
+    /// encoded.bytesize == 10
+    ///
+    /// encoded.encode(Encoding::UTF_8) == 'Héllo'
+    ///
+    /// string.encode(Encoding::US_ASCII) # Encoding::UndefinedConversionError
+    /// ```
+    pub fn encode(&self, encoding: &Encoding) -> Result<RString, Error> {
+        string::encode(self.value(), encoding.value())
+            .map(RString::from)
+            .map_err(|exception| Error::from_exception(AnyObject::from(exception)))
+    }
+
     /// Returns the length of the string in bytes
     ///
     /// # Examples
@@ -210,6 +573,61 @@ impl From<String> for RString {
     }
 }
 
+/// Strings are equal if they have the same contents, the same as Ruby `String#==`.
+///
+/// # Examples
+///
+/// ```
+/// use ruru::{RString, VM};
+/// # VM::init();
+///
+/// assert_eq!(RString::new("apple"), RString::new("apple"));
+/// assert!(RString::new("apple") != RString::new("pear"));
+/// ```
+///
+/// Ruby:
+///
+/// ```ruby
+/// 'apple' == 'apple'
+/// 'apple' != 'pear'
+/// ```
+impl PartialEq for RString {
+    fn eq(&self, other: &RString) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+/// Compares strings by their contents similarly to Ruby `String#<=>`.
+///
+/// # Examples
+///
+/// ```
+/// use ruru::{RString, VM};
+/// # VM::init();
+///
+/// let mut strings = vec![RString::new("pear"), RString::new("apple"), RString::new("orange")];
+///
+/// strings.sort_by(|a, b| a.partial_cmp(b).unwrap());
+///
+/// let strings: Vec<&str> = strings.iter().map(RString::to_str).collect();
+///
+/// assert_eq!(strings, vec!["apple", "orange", "pear"]);
+/// assert!(RString::new("apple") < RString::new("apples"));
+/// ```
+///
+/// Ruby:
+///
+/// ```ruby
+/// %w(pear apple orange).sort == %w(apple orange pear)
+///
+/// 'apple' < 'apples'
+/// ```
+impl PartialOrd for RString {
+    fn partial_cmp(&self, other: &RString) -> Option<Ordering> {
+        Some(string::compare(self.value(), other.value()).cmp(&0))
+    }
+}
+
 impl Object for RString {
     #[inline]
     fn value(&self) -> Value {
@@ -217,6 +635,12 @@ impl Object for RString {
     }
 }
 
+impl fmt::Debug for RString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.inspect())
+    }
+}
+
 impl VerifiedObject for RString {
     fn is_correct_type<T: Object>(object: &T) -> bool {
         object.value().ty() == ValueType::RString
@@ -226,3 +650,17 @@ impl VerifiedObject for RString {
         "Error converting to String"
     }
 }
+
+impl TryFrom<AnyObject> for RString {
+    type Error = Error;
+
+    fn try_from(object: AnyObject) -> Result<Self, Error> {
+        object.try_convert_to::<RString>()
+    }
+}
+
+impl From<RString> for AnyObject {
+    fn from(object: RString) -> Self {
+        object.to_any_object()
+    }
+}
@@ -1,13 +1,15 @@
-use std::convert::From;
+use std::convert::{From, TryFrom};
+use std::fmt;
 
 use binding::symbol;
 use binding::util;
+use result::Error;
 use types::{Value, ValueType};
 
-use {Object, VerifiedObject};
+use {AnyObject, Object, VerifiedObject};
 
 /// `Symbol`
-#[derive(Debug, PartialEq)]
+#[derive(PartialEq)]
 pub struct Symbol {
     value: Value,
 }
@@ -94,6 +96,28 @@ impl From<Value> for Symbol {
     }
 }
 
+/// Creates a new instance of Ruby `Symbol`, the same as `Symbol::new()`.
+///
+/// # Examples
+///
+/// ```
+/// use ruru::{Object, Symbol, VM};
+/// # VM::init();
+///
+/// let symbol = Symbol::from("port");
+///
+/// assert!(symbol.equals(&Symbol::new("port")));
+///
+/// let symbol: Symbol = "port".into();
+///
+/// assert_eq!(symbol.to_str(), "port");
+/// ```
+impl<'a> From<&'a str> for Symbol {
+    fn from(string: &'a str) -> Self {
+        Self::new(string)
+    }
+}
+
 impl Object for Symbol {
     #[inline]
     fn value(&self) -> Value {
@@ -101,6 +125,12 @@ impl Object for Symbol {
     }
 }
 
+impl fmt::Debug for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.inspect())
+    }
+}
+
 impl VerifiedObject for Symbol {
     fn is_correct_type<T: Object>(object: &T) -> bool {
         object.value().ty() == ValueType::Symbol
@@ -110,3 +140,17 @@ impl VerifiedObject for Symbol {
         "Error converting to Symbol"
     }
 }
+
+impl TryFrom<AnyObject> for Symbol {
+    type Error = Error;
+
+    fn try_from(object: AnyObject) -> Result<Self, Error> {
+        object.try_convert_to::<Symbol>()
+    }
+}
+
+impl From<Symbol> for AnyObject {
+    fn from(object: Symbol) -> Self {
+        object.to_any_object()
+    }
+}
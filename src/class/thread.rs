@@ -1,15 +1,17 @@
-use std::convert::From;
+use std::convert::{From, TryFrom};
+use std::fmt;
 
 use binding::thread;
-use types::Value;
+use result::Error;
+use types::{Value, ValueType};
 
 #[cfg(unix)]
 use types::RawFd;
 
-use {Class, Object, VerifiedObject};
+use {AnyObject, Class, Object, VerifiedObject};
 
 /// `Thread`
-#[derive(Debug, PartialEq)]
+#[derive(PartialEq)]
 pub struct Thread {
     value: Value,
 }
@@ -152,12 +154,32 @@ impl Object for Thread {
     }
 }
 
+impl fmt::Debug for Thread {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.inspect())
+    }
+}
+
 impl VerifiedObject for Thread {
     fn is_correct_type<T: Object>(object: &T) -> bool {
-        object.class() == Class::from_existing("Thread")
+        object.value().ty() == ValueType::Data && object.class() == Class::from_existing("Thread")
     }
 
     fn error_message() -> &'static str {
         "Error converting to Thread"
     }
 }
+
+impl TryFrom<AnyObject> for Thread {
+    type Error = Error;
+
+    fn try_from(object: AnyObject) -> Result<Self, Error> {
+        object.try_convert_to::<Thread>()
+    }
+}
+
+impl From<Thread> for AnyObject {
+    fn from(object: Thread) -> Self {
+        object.to_any_object()
+    }
+}
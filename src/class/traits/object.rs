@@ -1,14 +1,19 @@
+use std::cmp::Ordering;
 use std::convert::From;
 
 use binding::class;
-use binding::global::ValueType;
+use binding::closure;
+use binding::gc;
+use binding::module;
+use binding::global::{rb_cObject, ValueType};
 use binding::util as binding_util;
+use binding::vm;
 use result::{Error, Result};
 use typed_data::DataTypeWrapper;
 use types::{Callback, Value};
 use util;
 
-use {AnyObject, Boolean, Class, VerifiedObject};
+use {AnyObject, Array, Boolean, Class, Enumerator, Fixnum, Method, Module, Proc, VerifiedObject};
 
 /// `Object`
 ///
@@ -96,7 +101,7 @@ pub trait Object: From<Value> {
     /// let another_array = Array::new();
     ///
     /// array.singleton_class().define(|itself| {
-    ///     itself.attr_reader("modified");
+    ///     itself.attr_reader(&["modified"]);
     /// });
     ///
     /// assert!(array.respond_to("modified"));
@@ -120,6 +125,144 @@ pub trait Object: From<Value> {
         Class::from(class)
     }
 
+    /// Checks whether the object is an instance of given class or of one of its subclasses
+    /// (Ruby `is_a?` and `kind_of?`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Class, Fixnum, Object, VM};
+    /// # VM::init();
+    ///
+    /// let integer = Class::from_existing("Integer");
+    /// let string = Class::from_existing("String");
+    ///
+    /// assert!(Fixnum::new(1).is_a(&integer));
+    /// assert!(!Fixnum::new(1).is_a(&string));
+    ///
+    /// let parent = Class::new("Parent", None);
+    /// let child = Class::new("Child", Some(&parent)).new_instance(None);
+    ///
+    /// assert!(child.is_a(&parent));
+    /// assert!(!child.instance_of(&parent));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// 1.is_a?(Integer) == true
+    /// 1.is_a?(String) == false
+    ///
+    /// class Parent; end
+    /// class Child < Parent; end
+    ///
+    /// Child.new.is_a?(Parent) == true
+    /// Child.new.instance_of?(Parent) == false
+    /// ```
+    fn is_a(&self, class: &Class) -> bool {
+        class::is_kind_of(self.value(), class.value())
+    }
+
+    /// Checks whether the object is an instance of exactly the given class (Ruby `instance_of?`).
+    ///
+    /// Unlike `is_a()`, it returns `false` for instances of subclasses.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Class, Object, VM};
+    /// # VM::init();
+    ///
+    /// let parent = Class::new("Parent", None);
+    /// let child = Class::new("Child", Some(&parent));
+    ///
+    /// let object = child.new_instance(None);
+    ///
+    /// assert!(object.instance_of(&child));
+    /// assert!(!object.instance_of(&parent));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// class Parent; end
+    /// class Child < Parent; end
+    ///
+    /// object = Child.new
+    ///
+    /// object.instance_of?(Child) == true
+    /// object.instance_of?(Parent) == false
+    /// ```
+    fn instance_of(&self, class: &Class) -> bool {
+        class::is_instance_of(self.value(), class.value())
+    }
+
+    /// Checks whether the object is an instance of the class with given name or of one of its
+    /// subclasses.
+    ///
+    /// Nested classes can be given with `::` (for example, `Encoding::CompatibilityError`).
+    /// Returns `false` if the constant does not exist or is not a class or a module.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Class, Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let string = RString::new("Hello");
+    ///
+    /// assert!(string.is_a_named("String"));
+    /// assert!(string.is_a_named("Comparable"));
+    /// assert!(!string.is_a_named("Array"));
+    /// assert!(!string.is_a_named("SomethingThatDoesNotExist"));
+    ///
+    /// let parent = Class::new("Parent", None);
+    /// let child = Class::new("Child", Some(&parent)).new_instance(None);
+    ///
+    /// assert!(child.is_a_named("Parent"));
+    ///
+    /// let error = Class::from_existing("Encoding")
+    ///     .get_nested_class("CompatibilityError")
+    ///     .new_instance(None);
+    ///
+    /// assert!(error.is_a_named("Encoding::CompatibilityError"));
+    /// assert!(!error.is_a_named("Encoding::SomethingThatDoesNotExist"));
+    /// assert!(!string.is_a_named("String::Hello"));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// string = 'Hello'
+    ///
+    /// string.is_a?(String) == true
+    /// string.is_a?(Comparable) == true
+    /// string.is_a?(Array) == false
+    ///
+    /// defined?(SomethingThatDoesNotExist) == nil
+    ///
+    /// error = Encoding::CompatibilityError.new
+    ///
+    /// error.is_a?(Encoding::CompatibilityError) == true
+    /// defined?(Encoding::SomethingThatDoesNotExist) == nil
+    /// ```
+    fn is_a_named(&self, class_name: &str) -> bool {
+        let mut class = unsafe { rb_cObject };
+
+        // Nested constants like `Foo::Bar` are resolved one segment at a time
+        for name in class_name.split("::") {
+            let parent = class;
+
+            match vm::protect_value(|| binding_util::get_constant(name, parent)) {
+                Ok(constant) => class = constant,
+                Err(_) => return false,
+            }
+        }
+
+        self.protect_send("is_a?", &[AnyObject::from(class)])
+            .map_or(false, |result| result.value().is_true())
+    }
+
     /// Gets an immutable reference to the Rust structure which is wrapped into a Ruby object.
     ///
     /// See the documentation for `wrappable_struct!` macro for more information.
@@ -215,6 +358,72 @@ pub trait Object: From<Value> {
         class::get_data(self.value(), wrapper)
     }
 
+    /// Replaces the Rust structure which is wrapped into a Ruby object, for example, the default
+    /// one created by the allocation function of `Class::define_alloc_func_for()`.
+    ///
+    /// The previously wrapped structure is dropped. Raises `TypeError` if the object does not
+    /// wrap structures of type `T`.
+    ///
+    /// See the example for `Class::define_alloc_func_for()`.
+    fn set_wrapped_data<T>(&mut self, data: T, wrapper: &DataTypeWrapper<T>) {
+        class::set_data(self.value(), data, wrapper);
+    }
+
+    /// Marks the object to prevent its garbage collection (the same as `GC::mark()`).
+    ///
+    /// This function must be called from the `mark` block of `wrappable_struct!` for each Ruby
+    /// object stored in the wrapped Rust structure. Otherwise Ruby does not know that the object
+    /// is still referenced and it may be garbage collected.
+    ///
+    /// The contract of the `mark` block:
+    ///
+    ///  - it is called by Ruby during the mark phase of garbage collection only;
+    ///  - it must mark every Ruby object which is reachable from the wrapped structure;
+    ///  - it must not allocate new Ruby objects or call Ruby methods.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use] extern crate ruru;
+    /// #[macro_use] extern crate lazy_static;
+    ///
+    /// use ruru::{AnyObject, Array, Class, Object, RString, VM};
+    ///
+    /// pub struct Inventory {
+    ///     items: Array,
+    /// }
+    ///
+    /// wrappable_struct! {
+    ///     Inventory,
+    ///     InventoryWrapper,
+    ///     INVENTORY_WRAPPER,
+    ///
+    ///     mark(data) {
+    ///         data.items.mark_for_gc();
+    ///     }
+    /// }
+    ///
+    /// fn main() {
+    ///     # VM::init();
+    ///     let data_class = Class::from_existing("Data");
+    ///     let inventory_class = Class::new("Inventory", Some(&data_class));
+    ///
+    ///     let items = Array::new().push(RString::new("hammer"));
+    ///     let inventory: AnyObject =
+    ///         inventory_class.wrap_data(Inventory { items: items }, &*INVENTORY_WRAPPER);
+    ///
+    ///     Class::from_existing("GC").protect_send("start", &[]).unwrap();
+    ///
+    ///     let items = &inventory.get_data(&*INVENTORY_WRAPPER).items;
+    ///
+    ///     assert_eq!(items.length(), 1);
+    ///     assert_eq!(items.at(0).try_convert_to::<RString>().unwrap().to_str(), "hammer");
+    /// }
+    /// ```
+    fn mark_for_gc(&self) {
+        gc::mark(self.value());
+    }
+
     /// Wraps calls to the object.
     ///
     /// Mostly used to have Ruby-like class definition DSL.
@@ -255,7 +464,8 @@ pub trait Object: From<Value> {
     ///
     /// fn main() {
     ///     Class::new("Hello", None).define(|itself| {
-    ///         itself.attr_reader("reader");
+    ///         itself.attr_reader(&["reader"]);
+    ///         itself.attr_accessor(&["name"]);
     ///
     ///         itself.def_self("greeting", greeting);
     ///         itself.def("many_greetings", many_greetings);
@@ -272,6 +482,7 @@ pub trait Object: From<Value> {
     /// ```ruby
     /// class Hello
     ///   attr_reader :reader
+    ///   attr_accessor :name
     ///
     ///   def self.greeting
     ///     'Greeting from class'
@@ -572,93 +783,78 @@ pub trait Object: From<Value> {
         self.define_singleton_method(name, callback);
     }
 
-    /// Calls a given method on an object similarly to Ruby `Object#send` method
+    /// Defines a private method for the class (similar to Ruby `private def some_method`).
+    ///
+    /// Private methods can be called only without an explicit receiver, for example from other
+    /// methods of the same class.
     ///
     /// # Examples
     ///
     /// ```
-    /// use ruru::{Array, Fixnum, Object, RString, VM};
-    /// # VM::init();
+    /// #[macro_use]
+    /// extern crate ruru;
     ///
-    /// let array = Array::new().push(Fixnum::new(1));
-    /// let array_string =
-    ///     array
-    ///         .send("to_s", None)
-    ///         .try_convert_to::<RString>()
-    ///         .unwrap();
+    /// use ruru::{Class, Object, RString, VM};
     ///
-    /// assert_eq!(array_string.to_str(), "[1]");
-    /// ```
-    fn send(&self, method: &str, arguments: Option<&[AnyObject]>) -> AnyObject {
-        let arguments = util::arguments_to_values(arguments);
-        let result = binding_util::call_method(self.value(), method, arguments);
-
-        AnyObject::from(result)
-    }
-
-    /// Checks whether the object responds to given method
+    /// class!(Greeter);
     ///
-    /// # Examples
+    /// methods!(
+    ///     Greeter,
+    ///     itself,
     ///
-    /// ```
-    /// use ruru::{Array, Object, VM};
-    /// # VM::init();
+    ///     fn greeting() -> RString {
+    ///         RString::new("Hello")
+    ///     }
+    /// );
     ///
-    /// let array = Array::new();
+    /// fn main() {
+    ///     # VM::init();
+    ///     let mut class = Class::new("Greeter", None);
     ///
-    /// assert!(array.respond_to("push"));
-    /// assert!(!array.respond_to("something_else"));
-    /// ```
-    fn respond_to(&self, method: &str) -> bool {
-        class::respond_to(self.value(), method)
-    }
-
-    /// Checks whether the object is `nil`
+    ///     class.def_private("greeting", greeting);
     ///
-    /// # Examples
+    ///     let code = RString::new("def greet; \"#{greeting}, World!\"; end");
+    ///     class.protect_send("class_eval", &[code.to_any_object()]).unwrap();
     ///
-    /// ```
-    /// use ruru::{Hash, NilClass, Object, VM};
-    /// # VM::init();
+    ///     let greeter = class.new_instance(None);
     ///
-    /// assert!(NilClass::new().is_nil());
-    /// assert!(!Hash::new().is_nil());
+    ///     let greeting = greeter.protect_public_send("greeting", &[]).unwrap_err();
+    ///     let greet = greeter
+    ///         .protect_send("greet", &[])
+    ///         .unwrap()
+    ///         .try_convert_to::<RString>()
+    ///         .unwrap();
+    ///
+    ///     assert_eq!(greeting.to_exception(), Class::from_existing("NoMethodError"));
+    ///     assert_eq!(greet.to_str(), "Hello, World!");
+    /// }
     /// ```
     ///
     /// Ruby:
     ///
     /// ```ruby
-    /// nil.nil? == true
-    /// {}.nil? == false
-    /// ```
-    fn is_nil(&self) -> bool {
-        self.value().is_nil()
-    }
-
-    /// Converts struct to `AnyObject`
-    ///
-    /// See docs for `AnyObject` class for more details.
-    ///
-    /// # Examples
+    /// class Greeter
+    ///   def greet
+    ///     "#{greeting}, World!"
+    ///   end
     ///
-    /// ```
-    /// use ruru::{Array, Fixnum, Object, VM};
-    /// # VM::init();
+    ///   private
     ///
-    /// let array = Array::new().push(Fixnum::new(1));
-    /// let args = [Fixnum::new(1).to_any_object()];
-    /// let index =
-    ///     array
-    ///         .send("find_index", Some(&args))
-    ///         .try_convert_to::<Fixnum>();
+    ///   def greeting
+    ///     'Hello'
+    ///   end
+    /// end
     ///
-    /// assert_eq!(index, Ok(Fixnum::new(0)));
+    /// Greeter.new.greeting # raises NoMethodError
+    /// Greeter.new.greet == 'Hello, World!'
     /// ```
-    fn to_any_object(&self) -> AnyObject {
-        AnyObject::from(self.value())
+    fn def_private<I: Object, O: Object>(&mut self, name: &str, callback: Callback<I, O>) {
+        class::define_private_method(self.value(), name, callback);
     }
 
-    /// Gets an instance variable of object
+    /// Defines a protected method for the class (similar to Ruby `protected def some_method`).
+    ///
+    /// Protected methods can be called only from methods of instances of the same class.
     ///
     /// # Examples
     ///
@@ -666,115 +862,1178 @@ pub trait Object: From<Value> {
     /// #[macro_use]
     /// extern crate ruru;
     ///
-    /// use ruru::{AnyObject, Class, Fixnum, Object, VM};
+    /// use ruru::{Class, Fixnum, Object, VM};
     ///
-    /// class!(Counter);
+    /// class!(Account);
     ///
     /// methods!(
-    ///     Counter,
+    ///     Account,
     ///     itself,
     ///
-    ///     fn counter_initialize() -> AnyObject {
-    ///         itself.instance_variable_set("@state", Fixnum::new(0))
-    ///     }
-    ///
-    ///     fn counter_increment() -> AnyObject {
-    ///         // Using unsafe conversion, because we are sure that `@state` is always a `Fixnum`
-    ///         // and we don't provide an interface to set the value externally
-    ///         let state = unsafe {
-    ///             itself.instance_variable_get("@state").to::<Fixnum>().to_i64()
-    ///         };
-    ///
-    ///         itself.instance_variable_set("@state", Fixnum::new(state + 1))
-    ///     }
-    ///
-    ///     fn counter_state() -> Fixnum {
-    ///         unsafe { itself.instance_variable_get("@state").to::<Fixnum>() }
+    ///     fn balance() -> Fixnum {
+    ///         Fixnum::new(100)
     ///     }
     /// );
     ///
     /// fn main() {
     ///     # VM::init();
-    ///     let counter = Class::new("Counter", None).define(|itself| {
-    ///         itself.def("initialize", counter_initialize);
-    ///         itself.def("increment!", counter_increment);
-    ///         itself.def("state", counter_state);
-    ///     }).new_instance(None);
+    ///     let mut class = Class::new("Account", None);
     ///
-    ///     counter.send("increment!", None);
+    ///     class.def_protected("balance", balance);
     ///
-    ///     let new_state = counter.send("state", None).try_convert_to::<Fixnum>();
+    ///     let account = class.new_instance(None);
+    ///     let error = account.protect_public_send("balance", &[]).unwrap_err();
     ///
-    ///     assert_eq!(new_state, Ok(Fixnum::new(1)));
+    ///     assert_eq!(error.to_exception(), Class::from_existing("NoMethodError"));
     /// }
     /// ```
     ///
     /// Ruby:
     ///
     /// ```ruby
-    /// class Counter
-    ///   def initialize
-    ///     @state = 0
-    ///   end
-    ///
-    ///   def increment!
-    ///     @state += 1
-    ///   end
+    /// class Account
+    ///   protected
     ///
-    ///   def state
-    ///     @state
+    ///   def balance
+    ///     100
     ///   end
     /// end
     ///
-    /// counter = Counter.new
-    /// counter.increment!
-    ///
-    /// new_state = counter.state
-    ///
-    /// new_state == 1
+    /// Account.new.balance # raises NoMethodError
     /// ```
-    fn instance_variable_get(&self, variable: &str) -> AnyObject {
-        let result = class::instance_variable_get(self.value(), variable);
-
-        AnyObject::from(result)
+    fn def_protected<I: Object, O: Object>(&mut self, name: &str, callback: Callback<I, O>) {
+        class::define_protected_method(self.value(), name, callback);
     }
 
-    /// Sets an instance variable for object
+    /// Defines a singleton method on the object which calls a Rust closure.
+    ///
+    /// Unlike `define_singleton_method()`, the closure can capture Rust state. The method
+    /// receives its arguments as a slice of `AnyObject`s.
     ///
     /// # Examples
     ///
     /// ```
-    /// #[macro_use]
-    /// extern crate ruru;
+    /// use ruru::{AnyObject, Fixnum, Object, RString, VM};
+    /// # VM::init();
     ///
-    /// use ruru::{AnyObject, Class, Fixnum, Object, VM};
+    /// let mut object = RString::new("counter");
+    /// let mut count = 0;
     ///
-    /// class!(Counter);
+    /// object.def_closure("increment!", move |_arguments: &[AnyObject]| {
+    ///     count += 1;
     ///
-    /// methods!(
-    ///     Counter,
-    ///     itself,
+    ///     Fixnum::new(count)
+    /// });
     ///
-    ///     fn counter_initialize() -> AnyObject {
-    ///         itself.instance_variable_set("@state", Fixnum::new(0))
-    ///     }
+    /// let first = object.protect_send("increment!", &[]).unwrap().try_convert_to::<Fixnum>();
+    /// let second = object.protect_send("increment!", &[]).unwrap().try_convert_to::<Fixnum>();
     ///
-    ///     fn counter_increment() -> AnyObject {
-    ///         // Using unsafe conversion, because we are sure that `@state` is always a `Fixnum`
-    ///         // and we don't provide an interface to set the value externally
-    ///         let state = unsafe {
-    ///             itself.instance_variable_get("@state").to::<Fixnum>().to_i64()
-    ///         };
+    /// assert_eq!(first, Ok(Fixnum::new(1)));
+    /// assert_eq!(second, Ok(Fixnum::new(2)));
+    /// assert!(!RString::new("other").respond_to("increment!"));
+    /// ```
     ///
-    ///         itself.instance_variable_set("@state", Fixnum::new(state + 1))
-    ///     }
+    /// Ruby:
     ///
-    ///     fn counter_state() -> Fixnum {
-    ///         unsafe { itself.instance_variable_get("@state").to::<Fixnum>() }
-    ///     }
-    /// );
+    /// ```ruby
+    /// object = 'counter'
+    /// count = 0
     ///
-    /// fn main() {
+    /// object.define_singleton_method(:increment!) do
+    ///   count += 1
+    /// end
+    ///
+    /// object.increment! == 1
+    /// object.increment! == 2
+    /// ```
+    fn def_closure<F, O>(&mut self, name: &str, closure: F)
+    where
+        F: FnMut(&[AnyObject]) -> O + 'static,
+        O: Object,
+    {
+        let mut closure = closure;
+        let closure: closure::BoxedClosure =
+            Box::new(move |arguments| closure(arguments).to_any_object());
+
+        let rproc = closure::to_proc(closure::wrap(closure));
+
+        class::define_singleton_proc(self.value(), name, rproc);
+    }
+
+    /// Calls a given method on an object similarly to Ruby `Object#send` method
+    ///
+    /// **Deprecated:** an exception raised by the method is not caught. Use `protect_send()`
+    /// or `send_unchecked()` if the method can not raise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![allow(deprecated)]
+    /// use ruru::{Array, Fixnum, Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let array = Array::new().push(Fixnum::new(1));
+    /// let array_string =
+    ///     array
+    ///         .send("to_s", None)
+    ///         .try_convert_to::<RString>()
+    ///         .unwrap();
+    ///
+    /// assert_eq!(array_string.to_str(), "[1]");
+    /// ```
+    #[deprecated(note = "Use `protect_send()` or `send_unchecked()` instead")]
+    fn send(&self, method: &str, arguments: Option<&[AnyObject]>) -> AnyObject {
+        unsafe { self.send_unchecked(method, arguments) }
+    }
+
+    /// Calls a given method on an object without catching exceptions
+    ///
+    /// # Safety
+    ///
+    /// If the method raises an exception, the control is passed back to Ruby without returning
+    /// to Rust, so destructors of values on the Rust stack are not run. The caller must make sure
+    /// that the method does not raise or that no values with destructors are alive.
+    ///
+    /// `protect_send()` should be used in all other cases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Array, Fixnum, Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let array = Array::new().push(Fixnum::new(1));
+    /// let array_string = unsafe { array.send_unchecked("to_s", None) };
+    ///
+    /// assert_eq!(array_string.try_convert_to::<RString>().unwrap().to_str(), "[1]");
+    /// ```
+    unsafe fn send_unchecked(&self, method: &str, arguments: Option<&[AnyObject]>) -> AnyObject {
+        let arguments = util::arguments_to_values(arguments);
+        let result = binding_util::call_method(self.value(), method, arguments);
+
+        AnyObject::from(result)
+    }
+
+    /// Calls a given method on an object, catching exceptions raised by Ruby
+    ///
+    /// It is the default way to call Ruby methods dynamically. Unlike `send_unchecked()`, an
+    /// exception raised during the call does not propagate through Rust code.
+    /// It is returned as `Error::Exception` which contains the exception object and its message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Class, Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let string = RString::new("Hello, World!");
+    /// let arguments = [
+    ///     RString::new("World").to_any_object(),
+    ///     RString::new("Ruby").to_any_object(),
+    /// ];
+    ///
+    /// let result = string
+    ///     .protect_send("sub", &arguments)
+    ///     .and_then(|result| result.try_convert_to::<RString>());
+    ///
+    /// assert_eq!(result.unwrap().to_str(), "Hello, Ruby!");
+    ///
+    /// let error = string.protect_send("undefined_method", &[]).unwrap_err();
+    ///
+    /// assert_eq!(error.to_exception(), Class::from_existing("NoMethodError"));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// string = 'Hello, World!'
+    ///
+    /// string.sub('World', 'Ruby') == 'Hello, Ruby!'
+    ///
+    /// begin
+    ///   string.undefined_method
+    /// rescue NoMethodError => error
+    ///   error
+    /// end
+    /// ```
+    fn protect_send(&self, method: &str, arguments: &[AnyObject]) -> Result<AnyObject> {
+        let receiver = self.value();
+        let arguments = util::arguments_to_values(Some(arguments));
+
+        vm::protect_value(|| binding_util::call_method(receiver, method, arguments))
+            .map(AnyObject::from)
+            .map_err(|exception| Error::from_exception(AnyObject::from(exception)))
+    }
+
+    /// Calls a given public method on an object with protection (Ruby `public_send`)
+    ///
+    /// Unlike `protect_send()`, private and protected methods can not be called. In this case
+    /// `NoMethodError` is returned as `Error::Exception`. Use it when the name of the method
+    /// comes from untrusted input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Class, Fixnum, Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let code = RString::new("def visible; 1; end; private; def secret; 42; end");
+    ///
+    /// let class = Class::new("Vault", None);
+    /// class.protect_send("class_eval", &[code.to_any_object()]).unwrap();
+    ///
+    /// let vault = class.new_instance(None);
+    ///
+    /// let visible = vault.protect_public_send("visible", &[]);
+    /// let secret = vault.protect_public_send("secret", &[]);
+    ///
+    /// assert_eq!(visible.unwrap().try_convert_to::<Fixnum>(), Ok(Fixnum::new(1)));
+    /// assert_eq!(secret.unwrap_err().to_exception(), Class::from_existing("NoMethodError"));
+    ///
+    /// let secret = vault.protect_send("secret", &[]);
+    ///
+    /// assert_eq!(secret.unwrap().try_convert_to::<Fixnum>(), Ok(Fixnum::new(42)));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// class Vault
+    ///   def visible; 1; end
+    ///
+    ///   private
+    ///
+    ///   def secret; 42; end
+    /// end
+    ///
+    /// vault = Vault.new
+    ///
+    /// vault.public_send(:visible) == 1
+    /// vault.public_send(:secret) # NoMethodError: private method `secret' called
+    ///
+    /// vault.send(:secret) == 42
+    /// ```
+    fn protect_public_send(&self, method: &str, arguments: &[AnyObject]) -> Result<AnyObject> {
+        let receiver = self.value();
+        let arguments = util::arguments_to_values(Some(arguments));
+
+        vm::protect_value(|| binding_util::call_public_method(receiver, method, arguments))
+            .map(AnyObject::from)
+            .map_err(|exception| Error::from_exception(AnyObject::from(exception)))
+    }
+
+    /// Returns the method with given name bound to the object (Ruby `Object#method`)
+    ///
+    /// Raises `NameError` if the method is not defined.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Fixnum, Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let method = RString::new("Hello").method("length");
+    ///
+    /// assert_eq!(method.call(&[]).try_convert_to::<Fixnum>(), Ok(Fixnum::new(5)));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// method = 'Hello'.method(:length)
+    ///
+    /// method.call == 5
+    /// ```
+    fn method(&self, name: &str) -> Method {
+        Method::from(class::method(self.value(), name))
+    }
+
+    /// Returns names of public and protected methods of the object (Ruby `Object#methods`)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate ruru;
+    ///
+    /// use ruru::{Class, Fixnum, Object, VM};
+    ///
+    /// class!(Counter);
+    ///
+    /// methods!(
+    ///     Counter,
+    ///     itself,
+    ///
+    ///     fn counter_state() -> Fixnum {
+    ///         Fixnum::new(0)
+    ///     }
+    /// );
+    ///
+    /// fn main() {
+    ///     # VM::init();
+    ///     let counter = Class::new("Counter", None).define(|itself| {
+    ///         itself.def("state", counter_state);
+    ///     }).new_instance(None);
+    ///
+    ///     let methods = counter.methods();
+    ///
+    ///     assert!(methods.contains(&"state".to_string()));
+    ///     assert!(methods.contains(&"to_s".to_string()));
+    ///     assert!(!methods.contains(&"initialize".to_string()));
+    /// }
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// class Counter
+    ///   def state
+    ///     0
+    ///   end
+    /// end
+    ///
+    /// methods = Counter.new.methods
+    ///
+    /// methods.include?(:state) == true
+    /// methods.include?(:to_s) == true
+    /// methods.include?(:initialize) == false
+    /// ```
+    fn methods(&self) -> Vec<String> {
+        class::methods(self.value(), "methods")
+    }
+
+    /// Returns names of public methods of the object (Ruby `Object#public_methods`)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let methods = RString::new("Hello").public_methods();
+    ///
+    /// assert!(methods.contains(&"length".to_string()));
+    /// assert!(!methods.contains(&"initialize".to_string()));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// methods = 'Hello'.public_methods
+    ///
+    /// methods.include?(:length) == true
+    /// methods.include?(:initialize) == false
+    /// ```
+    fn public_methods(&self) -> Vec<String> {
+        class::methods(self.value(), "public_methods")
+    }
+
+    /// Returns names of singleton methods of the object (Ruby `Object#singleton_methods`)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate ruru;
+    ///
+    /// use ruru::{Class, Object, RString, VM};
+    ///
+    /// class!(Greeter);
+    ///
+    /// methods!(
+    ///     Greeter,
+    ///     itself,
+    ///
+    ///     fn greeter_greet() -> RString {
+    ///         RString::new("Hello!")
+    ///     }
+    /// );
+    ///
+    /// fn main() {
+    ///     # VM::init();
+    ///     let greeter = Class::new("Greeter", None).define(|itself| {
+    ///         itself.def_self("greet", greeter_greet);
+    ///     });
+    ///
+    ///     assert_eq!(greeter.singleton_methods(), vec!["greet".to_string()]);
+    ///     assert!(RString::new("Hello").singleton_methods().is_empty());
+    /// }
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// class Greeter
+    ///   def self.greet
+    ///     'Hello!'
+    ///   end
+    /// end
+    ///
+    /// Greeter.singleton_methods == [:greet]
+    /// 'Hello'.singleton_methods == []
+    /// ```
+    fn singleton_methods(&self) -> Vec<String> {
+        class::methods(self.value(), "singleton_methods")
+    }
+
+    /// Creates an `Enumerator` which iterates over the object using given method
+    /// (Ruby `to_enum`)
+    ///
+    /// The object must respond to `method`, which should yield the elements to a block (like
+    /// `each`). The method is not called until the enumerator is iterated, and the elements are
+    /// produced lazily, one by one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Class, Fixnum, Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let code = RString::new("def each; number = 0; loop { yield number += 1 }; end");
+    ///
+    /// let class = Class::new("Naturals", None);
+    /// class.protect_send("class_eval", &[code.to_any_object()]).unwrap();
+    ///
+    /// let naturals = class.new_instance(None).to_enum("each");
+    ///
+    /// // `Naturals#each` never ends, but only three elements are produced
+    /// let numbers: Vec<i64> = naturals
+    ///     .take(3)
+    ///     .map(|number| number.try_convert_to::<Fixnum>().unwrap().to_i64())
+    ///     .collect();
+    ///
+    /// assert_eq!(numbers, vec![1, 2, 3]);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// class Naturals
+    ///   def each
+    ///     number = 0
+    ///
+    ///     loop { yield number += 1 }
+    ///   end
+    /// end
+    ///
+    /// Naturals.new.to_enum(:each).take(3) == [1, 2, 3]
+    /// ```
+    fn to_enum(&self, method: &str) -> Enumerator {
+        Enumerator::from(class::to_enum(self.value(), method))
+    }
+
+    /// Extends the object with methods of a module.
+    ///
+    /// Unlike `define_singleton_method()`, the same module can be reused for several objects.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate ruru;
+    ///
+    /// use ruru::{Fixnum, Hash, Module, Object, Symbol, VM};
+    ///
+    /// methods!(
+    ///     Hash,
+    ///     itself,
+    ///
+    ///     fn port() -> Fixnum {
+    ///         itself.at(&Symbol::new("port")).try_convert_to::<Fixnum>().unwrap()
+    ///     }
+    /// );
+    ///
+    /// fn main() {
+    ///     # VM::init();
+    ///     let mut configuration = Module::new("Configuration");
+    ///
+    ///     configuration.define(|itself| {
+    ///         itself.def("port", port);
+    ///     });
+    ///
+    ///     let mut settings = Hash::new();
+    ///     settings.store(Symbol::new("port"), Fixnum::new(8080));
+    ///     settings.extend_with(&configuration);
+    ///
+    ///     let other_settings = Hash::new();
+    ///
+    ///     assert!(settings.respond_to("port"));
+    ///     assert!(!other_settings.respond_to("port"));
+    ///
+    ///     let port = settings.protect_send("port", &[]).unwrap().try_convert_to::<Fixnum>();
+    ///
+    ///     assert_eq!(port, Ok(Fixnum::new(8080)));
+    /// }
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// module Configuration
+    ///   def port
+    ///     self[:port]
+    ///   end
+    /// end
+    ///
+    /// settings = { port: 8080 }
+    /// settings.extend(Configuration)
+    ///
+    /// settings.respond_to?(:port) == true
+    /// {}.respond_to?(:port) == false
+    ///
+    /// settings.port == 8080
+    /// ```
+    fn extend_with(&mut self, module: &Module) -> &mut Self {
+        module::extend_object(self.value(), module.value());
+
+        self
+    }
+
+    /// Checks whether the object responds to given method
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Array, Object, VM};
+    /// # VM::init();
+    ///
+    /// let array = Array::new();
+    ///
+    /// assert!(array.respond_to("push"));
+    /// assert!(!array.respond_to("something_else"));
+    /// ```
+    fn respond_to(&self, method: &str) -> bool {
+        class::respond_to(self.value(), method)
+    }
+
+    /// Checks whether the object responds to given method including private and protected ones
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let string = RString::new("Hello");
+    ///
+    /// assert!(!string.respond_to("initialize"));
+    /// assert!(string.respond_to_including_private("initialize"));
+    /// assert!(!string.respond_to_including_private("something_else"));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// string = 'Hello'
+    ///
+    /// string.respond_to?(:initialize) == false
+    /// string.respond_to?(:initialize, true) == true
+    /// string.respond_to?(:something_else, true) == false
+    /// ```
+    fn respond_to_including_private(&self, method: &str) -> bool {
+        class::respond_to_including_private(self.value(), method)
+    }
+
+    /// Calls a given method on an object if the object responds to it
+    ///
+    /// Returns `None` if the object does not respond to the method. Otherwise the method is
+    /// called using `protect_send()` and exceptions raised by the method are returned as `Err`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Fixnum, Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let string = RString::new("Hello");
+    ///
+    /// let length = string
+    ///     .try_send("length", &[])
+    ///     .map(|result| result.and_then(|length| length.try_convert_to::<Fixnum>()));
+    ///
+    /// assert_eq!(length, Some(Ok(Fixnum::new(5))));
+    /// assert!(string.try_send("something_else", &[]).is_none());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// string = 'Hello'
+    ///
+    /// string.length if string.respond_to?(:length)
+    /// string.something_else if string.respond_to?(:something_else)
+    /// ```
+    fn try_send(&self, method: &str, arguments: &[AnyObject]) -> Option<Result<AnyObject>> {
+        if self.respond_to(method) {
+            Some(self.protect_send(method, arguments))
+        } else {
+            None
+        }
+    }
+
+    /// Evaluates a string containing Ruby source code in the context of the object
+    /// (Ruby `instance_eval`).
+    ///
+    /// The code is evaluated with protection. Exceptions, including `SyntaxError`s, are returned
+    /// as `Error::Exception`. The message of the error contains the line where the error occurred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Class, Fixnum, Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let mut object = Class::from_existing("Object").new_instance(None);
+    ///
+    /// let result = object.instance_eval_str("@count = 1 + 2");
+    ///
+    /// let count = object.instance_variable_get("@count");
+    ///
+    /// assert_eq!(result.unwrap().try_convert_to::<Fixnum>(), Ok(Fixnum::new(3)));
+    /// assert_eq!(count.try_convert_to::<Fixnum>(), Ok(Fixnum::new(3)));
+    ///
+    /// let error = object.instance_eval_str("1 +\n\n)").unwrap_err();
+    ///
+    /// assert_eq!(error.to_exception(), Class::from_existing("SyntaxError"));
+    /// assert!(error.to_string().contains(":3:"));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// object = Object.new
+    ///
+    /// object.instance_eval('@count = 1 + 2') == 3
+    /// object.instance_variable_get(:@count) == 3
+    ///
+    /// object.instance_eval("1 +\n\n)") # SyntaxError: (eval):3: syntax error
+    /// ```
+    fn instance_eval_str(&mut self, code: &str) -> Result<AnyObject> {
+        let object = self.value();
+
+        vm::protect_value(|| class::instance_eval(object, code))
+            .map(AnyObject::from)
+            .map_err(|exception| Error::from_exception(AnyObject::from(exception)))
+    }
+
+    /// Calls a proc in the context of the object passing given arguments to it
+    /// (Ruby `instance_exec`).
+    ///
+    /// The proc is called with protection, exceptions are returned as `Error::Exception`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Class, Fixnum, Object, Proc, RString, VM};
+    /// # VM::init();
+    ///
+    /// let mut object = Class::from_existing("Object").new_instance(None);
+    /// object.instance_variable_set("@base", Fixnum::new(10));
+    ///
+    /// let code = RString::new("proc { |number| @base + number }").to_any_object();
+    /// let block = Class::from_existing("Kernel")
+    ///     .protect_send("eval", &[code])
+    ///     .unwrap()
+    ///     .try_convert_to::<Proc>()
+    ///     .unwrap();
+    ///
+    /// let result = object.instance_exec_proc(&block, vec![Fixnum::new(5).to_any_object()]);
+    ///
+    /// assert_eq!(result.unwrap().try_convert_to::<Fixnum>(), Ok(Fixnum::new(15)));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// object = Object.new
+    /// object.instance_variable_set(:@base, 10)
+    ///
+    /// block = proc { |number| @base + number }
+    ///
+    /// object.instance_exec(5, &block) == 15
+    /// ```
+    fn instance_exec_proc(
+        &mut self,
+        block: &Proc,
+        arguments: Vec<AnyObject>,
+    ) -> Result<AnyObject> {
+        let object = self.value();
+        let block = block.value();
+        let arguments = arguments.iter().map(Object::value).collect();
+
+        vm::protect_value(|| class::instance_exec(object, block, arguments))
+            .map(AnyObject::from)
+            .map_err(|exception| Error::from_exception(AnyObject::from(exception)))
+    }
+
+    /// Checks whether the object is `nil`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Hash, NilClass, Object, VM};
+    /// # VM::init();
+    ///
+    /// assert!(NilClass::new().is_nil());
+    /// assert!(!Hash::new().is_nil());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// nil.nil? == true
+    /// {}.nil? == false
+    /// ```
+    fn is_nil(&self) -> bool {
+        self.value().is_nil()
+    }
+
+    /// Checks whether the object is equal to `other` using Ruby `==` method
+    ///
+    /// The method is called using `protect_send()`. If `==` raises an exception, the objects
+    /// are considered not equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Array, Fixnum, Hash, Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// assert!(RString::new("string").equals(&RString::new("string")));
+    /// assert!(!RString::new("string").equals(&RString::new("other string")));
+    ///
+    /// assert!(Fixnum::new(1).equals(&Fixnum::new(1)));
+    /// assert!(!Fixnum::new(1).equals(&Fixnum::new(2)));
+    ///
+    /// assert!(!Array::new().equals(&Hash::new()));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// 'string' == 'string'
+    /// 'string' != 'other string'
+    ///
+    /// 1 == 1
+    /// 1 != 2
+    ///
+    /// [] != {}
+    /// ```
+    fn equals<T: Object>(&self, other: &T) -> bool {
+        self.protect_send("==", &[other.to_any_object()])
+            .map(|result| result.value().is_true())
+            .unwrap_or(false)
+    }
+
+    /// Compares the object with `other` using Ruby `<=>` method
+    ///
+    /// Returns `Ok(None)` if the objects are not comparable (`<=>` returns `nil`). Exceptions
+    /// raised by `<=>` are returned as `Err`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    ///
+    /// use ruru::{Fixnum, Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let apple = RString::new("apple");
+    /// let banana = RString::new("banana");
+    ///
+    /// assert_eq!(apple.compare(&banana), Ok(Some(Ordering::Less)));
+    /// assert_eq!(banana.compare(&apple), Ok(Some(Ordering::Greater)));
+    /// assert_eq!(apple.compare(&apple), Ok(Some(Ordering::Equal)));
+    ///
+    /// assert_eq!(apple.compare(&Fixnum::new(1)), Ok(None));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// ('apple' <=> 'banana') == -1
+    /// ('banana' <=> 'apple') == 1
+    /// ('apple' <=> 'apple') == 0
+    ///
+    /// ('apple' <=> 1) == nil
+    /// ```
+    fn compare<T: Object>(&self, other: &T) -> Result<Option<Ordering>> {
+        let result = self.protect_send("<=>", &[other.to_any_object()])?;
+
+        if result.is_nil() {
+            return Ok(None);
+        }
+
+        match result.try_convert_to::<Fixnum>() {
+            Ok(number) => Ok(Some(number.to_i64().cmp(&0))),
+            Err(_) => {
+                let message = format!(
+                    "<=> returned {}, expected Integer or nil",
+                    result.class().name()
+                );
+
+                Err(Error::TypeError(message))
+            }
+        }
+    }
+
+    /// Checks whether the object and `other` have the same value and type using Ruby `eql?`
+    ///
+    /// The method is called using `protect_send()`. If `eql?` raises an exception, the objects
+    /// are considered not equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Fixnum, Float, Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// assert!(RString::new("string").is_eql(&RString::new("string")));
+    /// assert!(Fixnum::new(1).is_eql(&Fixnum::new(1)));
+    ///
+    /// assert!(Fixnum::new(1).equals(&Float::new(1.0)));
+    /// assert!(!Fixnum::new(1).is_eql(&Float::new(1.0)));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// 'string'.eql?('string') == true
+    /// 1.eql?(1) == true
+    ///
+    /// 1 == 1.0
+    /// 1.eql?(1.0) == false
+    /// ```
+    fn is_eql<T: Object>(&self, other: &T) -> bool {
+        self.protect_send("eql?", &[other.to_any_object()])
+            .map(|result| result.value().is_true())
+            .unwrap_or(false)
+    }
+
+    /// Checks whether the object and `other` are the same Ruby object (Ruby `equal?`)
+    ///
+    /// It compares the internal values of the objects and does not call any Ruby methods, so it
+    /// can be used together with `object_id()` to build identity maps keyed by Ruby objects.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let string = RString::new("string");
+    /// let same_string = string.to_any_object();
+    ///
+    /// assert!(string.is_identical(&same_string));
+    /// assert!(!string.is_identical(&RString::new("string")));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// string = 'string'
+    /// same_string = string
+    ///
+    /// string.equal?(same_string) == true
+    /// string.equal?('string') == false
+    /// ```
+    fn is_identical<T: Object>(&self, other: &T) -> bool {
+        self.value() == other.value()
+    }
+
+    /// Returns the object id (Ruby `object_id`)
+    ///
+    /// Each object has a unique id while it is alive. **Note:** ids of garbage collected objects
+    /// can be reused for new objects, so an identity map keyed by object ids must keep the
+    /// objects alive (for example, by marking them in the `mark` function of a wrapped struct).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let string = RString::new("string");
+    /// let same_string = string.to_any_object();
+    /// let other_string = RString::new("string");
+    ///
+    /// assert_eq!(string.object_id(), same_string.object_id());
+    /// assert!(string.object_id() != other_string.object_id());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// string = 'string'
+    /// same_string = string
+    /// other_string = 'string'
+    ///
+    /// string.object_id == same_string.object_id
+    /// string.object_id != other_string.object_id
+    /// ```
+    fn object_id(&self) -> u64 {
+        class::object_id(self.value())
+    }
+
+    /// Returns the hash value of the object (Ruby `hash`)
+    ///
+    /// Objects which are `eql?` to each other have the same hash value. The method is called
+    /// using `protect_send()`, so an exception raised by a custom `hash` method is returned
+    /// as `Err`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let hash = RString::new("string").ruby_hash();
+    /// let same_hash = RString::new("string").ruby_hash();
+    /// let other_hash = RString::new("other string").ruby_hash();
+    ///
+    /// assert!(hash.is_ok());
+    /// assert_eq!(hash, same_hash);
+    /// assert!(hash != other_hash);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// 'string'.hash == 'string'.hash
+    /// 'string'.hash != 'other string'.hash
+    /// ```
+    fn ruby_hash(&self) -> Result<i64> {
+        self.protect_send("hash", &[])
+            .and_then(|hash| hash.try_convert_to::<Fixnum>())
+            .map(|hash| hash.to_i64())
+    }
+
+    /// Returns a human-readable representation of the object (Ruby `inspect`)
+    ///
+    /// The method is called using `protect_send()`. If `inspect` raises an exception or does not
+    /// return a `String`, a default representation like `#<ClassName:0x00007f8a1c8b1234>` is
+    /// returned instead. Invalid UTF-8 sequences are replaced with `U+FFFD`.
+    ///
+    /// `Debug` implementations of built-in classes and classes defined with `class!` macro use
+    /// `inspect()` as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Array, Fixnum, Object, RString, Symbol, VM};
+    /// # VM::init();
+    ///
+    /// let array = Array::new().push(Fixnum::new(1)).push(Symbol::new("two"));
+    ///
+    /// assert_eq!(array.inspect(), "[1, :two]");
+    /// assert_eq!(format!("{:?}", array), "[1, :two]");
+    /// assert_eq!(RString::new("Hello").inspect(), "\"Hello\"");
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// [1, :two].inspect == '[1, :two]'
+    /// 'Hello'.inspect == '"Hello"'
+    /// ```
+    fn inspect(&self) -> String {
+        util::protected_string_representation(self, "inspect")
+    }
+
+    /// Returns a string representation of the object (Ruby `to_s`)
+    ///
+    /// The method is called using `protect_send()`. If `to_s` raises an exception or does not
+    /// return a `String`, a default representation like `#<ClassName:0x00007f8a1c8b1234>` is
+    /// returned instead. Invalid UTF-8 sequences are replaced with `U+FFFD`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Fixnum, Object, RString, Symbol, VM};
+    /// # VM::init();
+    ///
+    /// assert_eq!(Fixnum::new(1).to_s_string(), "1");
+    /// assert_eq!(Symbol::new("hello").to_s_string(), "hello");
+    /// assert_eq!(RString::new("Hello").to_s_string(), "Hello");
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// 1.to_s == '1'
+    /// :hello.to_s == 'hello'
+    /// 'Hello'.to_s == 'Hello'
+    /// ```
+    fn to_s_string(&self) -> String {
+        util::protected_string_representation(self, "to_s")
+    }
+
+    /// Converts struct to `AnyObject`
+    ///
+    /// See docs for `AnyObject` class for more details.
+    ///
+    /// Every built-in class and every class defined with `class!` macro also implements
+    /// `From<_> for AnyObject`, so generic code can accept `T: Into<AnyObject>` and take objects
+    /// by value. A blanket implementation for all `Object`s is not possible, because it would
+    /// conflict with `From<AnyObject> for AnyObject`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Array, Fixnum, Object, VM};
+    /// # VM::init();
+    ///
+    /// let array = Array::new().push(Fixnum::new(1));
+    /// let args = [Fixnum::new(1).to_any_object()];
+    /// let index =
+    ///     array
+    ///         .protect_send("find_index", &args)
+    ///         .unwrap()
+    ///         .try_convert_to::<Fixnum>();
+    ///
+    /// assert_eq!(index, Ok(Fixnum::new(0)));
+    /// ```
+    ///
+    /// Passing objects to functions which expect `AnyObject`:
+    ///
+    /// ```
+    /// use ruru::{AnyObject, Fixnum, Object, VM};
+    /// # VM::init();
+    ///
+    /// fn inspect(object: AnyObject) -> String {
+    ///     object.protect_send("inspect", &[]).unwrap().to_s_string()
+    /// }
+    ///
+    /// fn inspect_any<T: Into<AnyObject>>(object: T) -> String {
+    ///     inspect(object.into())
+    /// }
+    ///
+    /// let number = Fixnum::new(42);
+    ///
+    /// assert_eq!(inspect(number.to_any_object()), "42");
+    /// assert_eq!(inspect_any(number), "42");
+    /// ```
+    fn to_any_object(&self) -> AnyObject {
+        AnyObject::from(self.value())
+    }
+
+    /// Gets an instance variable of object
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate ruru;
+    ///
+    /// use ruru::{AnyObject, Class, Fixnum, Object, VM};
+    ///
+    /// class!(Counter);
+    ///
+    /// methods!(
+    ///     Counter,
+    ///     itself,
+    ///
+    ///     fn counter_initialize() -> AnyObject {
+    ///         itself.instance_variable_set("@state", Fixnum::new(0))
+    ///     }
+    ///
+    ///     fn counter_increment() -> AnyObject {
+    ///         // Using unsafe conversion, because we are sure that `@state` is always a `Fixnum`
+    ///         // and we don't provide an interface to set the value externally
+    ///         let state = unsafe {
+    ///             itself.instance_variable_get("@state").to::<Fixnum>().to_i64()
+    ///         };
+    ///
+    ///         itself.instance_variable_set("@state", Fixnum::new(state + 1))
+    ///     }
+    ///
+    ///     fn counter_state() -> Fixnum {
+    ///         unsafe { itself.instance_variable_get("@state").to::<Fixnum>() }
+    ///     }
+    /// );
+    ///
+    /// fn main() {
+    ///     # VM::init();
+    ///     let counter = Class::new("Counter", None).define(|itself| {
+    ///         itself.def("initialize", counter_initialize);
+    ///         itself.def("increment!", counter_increment);
+    ///         itself.def("state", counter_state);
+    ///     }).new_instance(None);
+    ///
+    ///     counter.protect_send("increment!", &[]).unwrap();
+    ///
+    ///     let new_state = counter.protect_send("state", &[]).unwrap().try_convert_to::<Fixnum>();
+    ///
+    ///     assert_eq!(new_state, Ok(Fixnum::new(1)));
+    /// }
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// class Counter
+    ///   def initialize
+    ///     @state = 0
+    ///   end
+    ///
+    ///   def increment!
+    ///     @state += 1
+    ///   end
+    ///
+    ///   def state
+    ///     @state
+    ///   end
+    /// end
+    ///
+    /// counter = Counter.new
+    /// counter.increment!
+    ///
+    /// new_state = counter.state
+    ///
+    /// new_state == 1
+    /// ```
+    fn instance_variable_get(&self, variable: &str) -> AnyObject {
+        let result = class::instance_variable_get(self.value(), variable);
+
+        AnyObject::from(result)
+    }
+
+    /// Sets an instance variable for object
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate ruru;
+    ///
+    /// use ruru::{AnyObject, Class, Fixnum, Object, VM};
+    ///
+    /// class!(Counter);
+    ///
+    /// methods!(
+    ///     Counter,
+    ///     itself,
+    ///
+    ///     fn counter_initialize() -> AnyObject {
+    ///         itself.instance_variable_set("@state", Fixnum::new(0))
+    ///     }
+    ///
+    ///     fn counter_increment() -> AnyObject {
+    ///         // Using unsafe conversion, because we are sure that `@state` is always a `Fixnum`
+    ///         // and we don't provide an interface to set the value externally
+    ///         let state = unsafe {
+    ///             itself.instance_variable_get("@state").to::<Fixnum>().to_i64()
+    ///         };
+    ///
+    ///         itself.instance_variable_set("@state", Fixnum::new(state + 1))
+    ///     }
+    ///
+    ///     fn counter_state() -> Fixnum {
+    ///         unsafe { itself.instance_variable_get("@state").to::<Fixnum>() }
+    ///     }
+    /// );
+    ///
+    /// fn main() {
     ///     # VM::init();
     ///     let counter = Class::new("Counter", None).define(|itself| {
     ///         itself.def("initialize", counter_initialize);
@@ -782,9 +2041,9 @@ pub trait Object: From<Value> {
     ///         itself.def("state", counter_state);
     ///     }).new_instance(None);
     ///
-    ///     counter.send("increment!", None);
+    ///     counter.protect_send("increment!", &[]).unwrap();
     ///
-    ///     let new_state = counter.send("state", None).try_convert_to::<Fixnum>();
+    ///     let new_state = counter.protect_send("state", &[]).unwrap().try_convert_to::<Fixnum>();
     ///
     ///     assert_eq!(new_state, Ok(Fixnum::new(1)));
     /// }
@@ -820,6 +2079,180 @@ pub trait Object: From<Value> {
         AnyObject::from(result)
     }
 
+    /// Gets an instance variable of object, checking the name of the variable first
+    ///
+    /// Returns `Error::NameError` if `variable` is not a valid instance variable name (for
+    /// example, it does not start with `@`) instead of letting Ruby raise an exception.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate ruru;
+    ///
+    /// use ruru::result::Error;
+    /// use ruru::{AnyObject, Class, Fixnum, NilClass, Object, RString, VM};
+    ///
+    /// class!(Greeter);
+    ///
+    /// methods!(
+    ///     Greeter,
+    ///     itself,
+    ///
+    ///     fn greeter_initialize(name: RString) -> NilClass {
+    ///         itself.try_instance_variable_set("@name", name.unwrap()).unwrap();
+    ///         itself.try_instance_variable_set("@count", Fixnum::new(0)).unwrap();
+    ///
+    ///         NilClass::new()
+    ///     }
+    ///
+    ///     fn greeter_greet() -> RString {
+    ///         let name = itself.try_instance_variable_get("@name")
+    ///             .and_then(|name| name.try_convert_to::<RString>())
+    ///             .unwrap();
+    ///
+    ///         let count = itself.try_instance_variable_get("@count")
+    ///             .and_then(|count| count.try_convert_to::<Fixnum>())
+    ///             .unwrap();
+    ///
+    ///         itself
+    ///             .try_instance_variable_set("@count", Fixnum::new(count.to_i64() + 1))
+    ///             .unwrap();
+    ///
+    ///         RString::new(&format!("Hello, {}!", name.to_str()))
+    ///     }
+    /// );
+    ///
+    /// fn main() {
+    ///     # VM::init();
+    ///     let greeter = Class::new("Greeter", None).define(|itself| {
+    ///         itself.def("initialize", greeter_initialize);
+    ///         itself.def("greet", greeter_greet);
+    ///     }).new_instance(Some(&[RString::new("World").to_any_object()]));
+    ///
+    ///     greeter.protect_send("greet", &[]).unwrap();
+    ///
+    ///     let greeting = greeter
+    ///         .protect_send("greet", &[])
+    ///         .unwrap()
+    ///         .try_convert_to::<RString>()
+    ///         .unwrap();
+    ///     let count = greeter.try_instance_variable_get("@count").unwrap();
+    ///
+    ///     assert_eq!(greeting.to_str(), "Hello, World!");
+    ///     assert_eq!(count.try_convert_to::<Fixnum>(), Ok(Fixnum::new(2)));
+    ///
+    ///     match greeter.try_instance_variable_get("count") {
+    ///         Err(Error::NameError { .. }) => {},
+    ///         _ => panic!("expected NameError"),
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// class Greeter
+    ///   def initialize(name)
+    ///     @name = name
+    ///     @count = 0
+    ///   end
+    ///
+    ///   def greet
+    ///     @count += 1
+    ///
+    ///     "Hello, #{@name}!"
+    ///   end
+    /// end
+    ///
+    /// greeter = Greeter.new('World')
+    /// greeter.greet
+    ///
+    /// greeter.greet == 'Hello, World!'
+    /// greeter.instance_variable_get(:@count) == 2
+    /// ```
+    fn try_instance_variable_get(&self, variable: &str) -> Result<AnyObject> {
+        util::check_instance_variable_name(variable)
+            .map(|_| self.instance_variable_get(variable))
+    }
+
+    /// Sets an instance variable for object, checking the name of the variable first
+    ///
+    /// Returns `Error::NameError` if `variable` is not a valid instance variable name (for
+    /// example, it does not start with `@`) instead of letting Ruby raise an exception.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::result::Error;
+    /// use ruru::{Fixnum, Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let mut object = RString::new("Hello");
+    ///
+    /// let result = object.try_instance_variable_set("@count", Fixnum::new(1));
+    ///
+    /// assert_eq!(result.unwrap().try_convert_to::<Fixnum>(), Ok(Fixnum::new(1)));
+    ///
+    /// match object.try_instance_variable_set("count", Fixnum::new(1)) {
+    ///     Err(Error::NameError { .. }) => {},
+    ///     _ => panic!("expected NameError"),
+    /// }
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// object = 'Hello'
+    ///
+    /// object.instance_variable_set(:@count, 1) == 1
+    /// object.instance_variable_set(:count, 1) # NameError
+    /// ```
+    fn try_instance_variable_set<T: Object>(
+        &mut self,
+        variable: &str,
+        value: T,
+    ) -> Result<AnyObject> {
+        util::check_instance_variable_name(variable)
+            .map(|_| self.instance_variable_set(variable, value))
+    }
+
+    /// Returns names of instance variables of object
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Fixnum, Object, RString, Symbol, VM};
+    /// # VM::init();
+    ///
+    /// let mut object = RString::new("Hello");
+    ///
+    /// object.instance_variable_set("@first", Fixnum::new(1));
+    /// object.instance_variable_set("@second", Fixnum::new(2));
+    ///
+    /// let variables = object.instance_variables();
+    ///
+    /// assert_eq!(variables.length(), 2);
+    /// assert_eq!(variables.at(0).try_convert_to::<Symbol>(), Ok(Symbol::new("@first")));
+    /// assert_eq!(variables.at(1).try_convert_to::<Symbol>(), Ok(Symbol::new("@second")));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// object = 'Hello'
+    ///
+    /// object.instance_variable_set(:@first, 1)
+    /// object.instance_variable_set(:@second, 2)
+    ///
+    /// object.instance_variables == [:@first, :@second]
+    /// ```
+    fn instance_variables(&self) -> Array {
+        let result = class::instance_variables(self.value());
+
+        Array::from(result)
+    }
+
     /// Returns the freeze status of the object.
     ///
     /// # Examples
@@ -872,6 +2305,160 @@ pub trait Object: From<Value> {
         Self::from(result)
     }
 
+    /// Recursively freezes the object and all the objects reachable from it.
+    ///
+    /// Elements of arrays and keys and values of hashes are frozen, other objects are frozen
+    /// without looking into them. Cyclic structures are supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Array, Hash, Object, RString, Symbol, VM};
+    /// # VM::init();
+    ///
+    /// let hosts = Array::new().push(RString::new("localhost"));
+    ///
+    /// let mut config = Hash::new();
+    /// config.store(Symbol::new("hosts"), hosts);
+    ///
+    /// let config = config.deep_freeze();
+    ///
+    /// let hosts = config.at(&Symbol::new("hosts")).try_convert_to::<Array>().unwrap();
+    /// let host = hosts.at(0);
+    ///
+    /// assert!(config.is_frozen());
+    /// assert!(hosts.is_frozen());
+    /// assert!(host.is_frozen());
+    ///
+    /// let result = host.protect_send("<<", &[RString::new(".local").to_any_object()]);
+    ///
+    /// assert!(result.is_err());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// def deep_freeze(object)
+    ///   case object
+    ///   when Array then object.each { |element| deep_freeze(element) }
+    ///   when Hash then object.each { |key, value| deep_freeze(key); deep_freeze(value) }
+    ///   end
+    ///
+    ///   object.freeze
+    /// end
+    ///
+    /// config = deep_freeze(hosts: ['localhost'])
+    ///
+    /// config[:hosts][0] << '.local' # raises FrozenError
+    /// ```
+    fn deep_freeze(&mut self) -> Self {
+        let result = class::deep_freeze(self.value());
+
+        Self::from(result)
+    }
+
+    /// Creates a shallow copy of the object (Ruby `dup`).
+    ///
+    /// Unlike `clone_object()`, the copy is not frozen and does not have the singleton class of
+    /// the original object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let frozen_string = RString::new("String").freeze();
+    /// let copy = frozen_string.dup();
+    ///
+    /// assert!(!copy.is_frozen());
+    /// assert!(copy.equals(&frozen_string));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// frozen_string = 'String'.freeze
+    /// copy = frozen_string.dup
+    ///
+    /// copy.frozen? == false
+    /// copy == frozen_string
+    /// ```
+    fn dup(&self) -> AnyObject {
+        let result = class::dup(self.value());
+
+        AnyObject::from(result)
+    }
+
+    /// Creates a shallow copy of the object (Ruby `clone`).
+    ///
+    /// The copy keeps the frozen state and the singleton class of the original object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let frozen_string = RString::new("String").freeze();
+    /// let copy = frozen_string.clone_object();
+    ///
+    /// assert!(copy.is_frozen());
+    /// assert!(copy.equals(&frozen_string));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// frozen_string = 'String'.freeze
+    /// copy = frozen_string.clone
+    ///
+    /// copy.frozen? == true
+    /// copy == frozen_string
+    /// ```
+    fn clone_object(&self) -> AnyObject {
+        let result = class::clone(self.value());
+
+        AnyObject::from(result)
+    }
+
+    /// Creates a frozen shallow copy of the object.
+    ///
+    /// The original object stays unfrozen, so it can be modified further, while the copy can be
+    /// shared as an immutable snapshot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Array, Fixnum, Object, VM};
+    /// # VM::init();
+    ///
+    /// let mut array = Array::new().push(Fixnum::new(1));
+    /// let snapshot = array.frozen_dup();
+    ///
+    /// array.push(Fixnum::new(2));
+    ///
+    /// assert!(snapshot.is_frozen());
+    /// assert!(!array.is_frozen());
+    /// assert_eq!(snapshot.try_convert_to::<Array>().unwrap().length(), 1);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// array = [1]
+    /// snapshot = array.dup.freeze
+    ///
+    /// array << 2
+    ///
+    /// snapshot.frozen? == true
+    /// array.frozen? == false
+    /// snapshot.length == 1
+    /// ```
+    fn frozen_dup(&self) -> AnyObject {
+        self.dup().freeze()
+    }
+
     /// Unsafely casts current object to the specified Ruby type
     ///
     /// This operation in unsafe, because it does not perform any validations on the object, but
@@ -1019,9 +2606,37 @@ pub trait Object: From<Value> {
         }
     }
 
+    /// Safely casts current object to the specified Ruby type
+    ///
+    /// It is the same as `try_convert_to()`, but reads better at call sites. Unlike `to()`,
+    /// it checks the type of the object and returns `Error::TypeError` if it is not correct.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::result::Error;
+    /// use ruru::{Fixnum, Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let fixnum = Fixnum::new(1).to_any_object();
+    ///
+    /// assert_eq!(fixnum.cast::<Fixnum>(), Ok(Fixnum::new(1)));
+    ///
+    /// let string = RString::new("string").to_any_object();
+    /// let expected_error = Error::TypeError("Error converting to Fixnum".to_string());
+    ///
+    /// assert_eq!(string.cast::<Fixnum>(), Err(expected_error));
+    /// ```
+    fn cast<T: VerifiedObject>(&self) -> Result<T> {
+        self.try_convert_to::<T>()
+    }
+
     /// Determines the value type of the object
     ///
-    /// # Example
+    /// The result can be used to `match` on the type of an object instead of trying to convert
+    /// it to each of the expected types.
+    ///
+    /// # Examples
     ///
     /// ```
     /// use ruru::{AnyObject, Fixnum, Object, VM};
@@ -1032,6 +2647,34 @@ pub trait Object: From<Value> {
     ///
     /// assert_eq!(any_object.ty(), ValueType::Fixnum);
     /// ```
+    ///
+    /// ### Dispatching on the type
+    ///
+    /// ```
+    /// use ruru::{Array, Fixnum, Object, RString, Symbol, VM};
+    /// use ruru::types::RubyType;
+    /// # VM::init();
+    ///
+    /// let array = Array::new()
+    ///     .push(RString::new("one"))
+    ///     .push(Fixnum::new(2))
+    ///     .push(RString::new("three"))
+    ///     .push(Symbol::new("four"));
+    ///
+    /// let mut strings = 0;
+    /// let mut fixnums = 0;
+    ///
+    /// for item in array.into_iter() {
+    ///     match item.ty() {
+    ///         RubyType::RString => strings += 1,
+    ///         RubyType::Fixnum => fixnums += 1,
+    ///         _ => {}
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(strings, 2);
+    /// assert_eq!(fixnums, 1);
+    /// ```
     fn ty(&self) -> ValueType {
         self.value().ty()
     }
@@ -0,0 +1,118 @@
+use std::convert::{From, TryFrom};
+use std::fmt;
+
+use binding::{method, symbol};
+use result::Error;
+use types::{Value, ValueType};
+
+use {AnyObject, Class, Method, Object, VerifiedObject};
+
+/// `UnboundMethod`
+///
+/// A method which is not bound to a receiver. It can be retrieved using
+/// `Class::instance_method()` or `Method::unbind()` and bound to any instance of the class
+/// (or its subclasses) with `UnboundMethod::bind()`.
+#[derive(PartialEq)]
+pub struct UnboundMethod {
+    value: Value,
+}
+
+impl UnboundMethod {
+    /// Binds the method to `object`.
+    ///
+    /// Raises `TypeError` if `object` is not an instance of the class the method was
+    /// retrieved from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Class, Fixnum, Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let length = Class::from_existing("String").instance_method("length").unwrap();
+    ///
+    /// let hello = length.bind(RString::new("Hello").to_any_object());
+    /// let hi = length.bind(RString::new("Hi").to_any_object());
+    ///
+    /// assert_eq!(hello.call(&[]).try_convert_to::<Fixnum>(), Ok(Fixnum::new(5)));
+    /// assert_eq!(hi.call(&[]).try_convert_to::<Fixnum>(), Ok(Fixnum::new(2)));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// length = String.instance_method(:length)
+    ///
+    /// length.bind('Hello').call == 5
+    /// length.bind('Hi').call == 2
+    /// ```
+    pub fn bind(&self, object: AnyObject) -> Method {
+        Method::from(method::bind(self.value(), object.value()))
+    }
+
+    /// Returns the name of the method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Class, VM};
+    /// # VM::init();
+    ///
+    /// let length = Class::from_existing("String").instance_method("length").unwrap();
+    ///
+    /// assert_eq!(length.name(), "length");
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// String.instance_method(:length).name == :length
+    /// ```
+    pub fn name(&self) -> String {
+        symbol::value_to_string(method::name(self.value()))
+    }
+}
+
+impl From<Value> for UnboundMethod {
+    fn from(value: Value) -> Self {
+        UnboundMethod { value: value }
+    }
+}
+
+impl Object for UnboundMethod {
+    #[inline]
+    fn value(&self) -> Value {
+        self.value
+    }
+}
+
+impl fmt::Debug for UnboundMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.inspect())
+    }
+}
+
+impl VerifiedObject for UnboundMethod {
+    fn is_correct_type<T: Object>(object: &T) -> bool {
+        object.value().ty() == ValueType::Data &&
+            object.class() == Class::from_existing("UnboundMethod")
+    }
+
+    fn error_message() -> &'static str {
+        "Error converting to UnboundMethod"
+    }
+}
+
+impl TryFrom<AnyObject> for UnboundMethod {
+    type Error = Error;
+
+    fn try_from(object: AnyObject) -> Result<Self, Error> {
+        object.try_convert_to::<UnboundMethod>()
+    }
+}
+
+impl From<UnboundMethod> for AnyObject {
+    fn from(object: UnboundMethod) -> Self {
+        object.to_any_object()
+    }
+}
@@ -1,9 +1,13 @@
 use std::slice;
 
+use binding::class;
+use binding::gc;
+use binding::global::rb_cObject;
 use binding::vm;
-use types::{Argc, Value};
+use result::Error;
+use types::{Argc, Callback, Value};
 
-use {AnyObject, Class, Object, Proc};
+use {AnyObject, Class, KeywordArguments, NilClass, Object, Proc};
 
 /// Virtual Machine and helpers
 pub struct VM;
@@ -59,6 +63,160 @@ impl VM {
         vm::require(name);
     }
 
+    /// Defines a new class or returns an existing one with the same name.
+    ///
+    /// `superclass` can receive the following values:
+    ///
+    ///  - `None` to inherit from `Object` class;
+    ///  - `Some(&Class)` to inherit from the given class
+    ///
+    /// Unlike `Class::new()`, this function does not let the exception propagate when the
+    /// class cannot be defined. It returns `Err` if the constant is already defined and is not
+    /// a class, or if the existing class has a different superclass.
+    ///
+    /// It is useful when a class may be reopened, for example, when a library is loaded twice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Class, VM};
+    /// # VM::init();
+    ///
+    /// let record_class = VM::define_class("Record", None).unwrap();
+    /// let same_record_class = VM::define_class("Record", None).unwrap();
+    ///
+    /// assert_eq!(record_class, same_record_class);
+    ///
+    /// let string_class = Class::from_existing("String");
+    /// let superclass_mismatch = VM::define_class("Record", Some(&string_class)).unwrap_err();
+    ///
+    /// assert_eq!(superclass_mismatch.to_exception(), Class::from_existing("TypeError"));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// class Record
+    /// end
+    ///
+    /// # reopens the class
+    /// class Record
+    /// end
+    ///
+    /// # TypeError: superclass mismatch for class Record
+    /// class Record < String
+    /// end
+    /// ```
+    pub fn define_class(name: &str, superclass: Option<&Class>) -> Result<Class, Error> {
+        let superclass = match superclass {
+            Some(class) => class.value(),
+            None => unsafe { rb_cObject },
+        };
+
+        vm::protect_value(|| class::define_class(name, superclass))
+            .map(Class::from)
+            .map_err(|exception| Error::from_exception(AnyObject::from(exception)))
+    }
+
+    /// Writes the object to `$stdout` followed by a newline (Ruby `puts`).
+    ///
+    /// The object is formatted the same way as in Ruby, for example, each element of an array is
+    /// written on a separate line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Array, Class, Fixnum, Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let eval = |code: &str| {
+    ///     let code = RString::new(code).to_any_object();
+    ///
+    ///     Class::from_existing("Object").protect_send("eval", &[code]).unwrap()
+    /// };
+    ///
+    /// eval("require 'stringio'; $stdout = StringIO.new");
+    ///
+    /// VM::puts(&Array::new().push(Fixnum::new(1)).push(RString::new("two")));
+    /// VM::print(&RString::new("three"));
+    ///
+    /// let output = eval("$stdout.string.tap { $stdout = STDOUT }").try_convert_to::<RString>();
+    ///
+    /// assert_eq!(output.unwrap().to_str(), "1\ntwo\nthree");
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// puts [1, 'two']
+    /// print 'three'
+    /// ```
+    pub fn puts<T: Object>(object: &T) {
+        vm::call_kernel_method("puts", Some(vec![object.value()]));
+    }
+
+    /// Writes the object to `$stdout` without a newline (Ruby `print`).
+    ///
+    /// See the example for `puts()`.
+    pub fn print<T: Object>(object: &T) {
+        vm::call_kernel_method("print", Some(vec![object.value()]));
+    }
+
+    /// Defines a global function which can be called from everywhere in Ruby.
+    ///
+    /// The function is defined as a private method of `Kernel` and as a singleton method of
+    /// `Kernel` (similar to `rb_define_global_function()` in C API).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate ruru;
+    ///
+    /// use ruru::{AnyObject, Class, Object, RString, VM};
+    ///
+    /// methods!(
+    ///     AnyObject,
+    ///     itself,
+    ///
+    ///     fn ruru_hello(name: RString) -> RString {
+    ///         RString::new(&format!("Hello, {}!", name.unwrap().to_str()))
+    ///     }
+    /// );
+    ///
+    /// fn main() {
+    ///     # VM::init();
+    ///     VM::define_global_function("ruru_hello", ruru_hello);
+    ///
+    ///     let code = RString::new("ruru_hello('World')");
+    ///
+    ///     let greeting = Class::from_existing("Object")
+    ///         .protect_send("eval", &[code.to_any_object()])
+    ///         .unwrap()
+    ///         .try_convert_to::<RString>()
+    ///         .unwrap();
+    ///
+    ///     assert_eq!(greeting.to_str(), "Hello, World!");
+    /// }
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// module Kernel
+    ///   module_function
+    ///
+    ///   def ruru_hello(name)
+    ///     "Hello, #{name}!"
+    ///   end
+    /// end
+    ///
+    /// ruru_hello('World') == 'Hello, World!'
+    /// ```
+    pub fn define_global_function<I: Object, O: Object>(name: &str, callback: Callback<I, O>) {
+        vm::define_global_function(name, callback);
+    }
+
     /// Raises an exception.
     ///
     /// # Examples
@@ -102,6 +260,36 @@ impl VM {
         vm::raise(exception.value(), message);
     }
 
+    /// Raises an exception corresponding to the given `Error`.
+    ///
+    /// The class of the exception is chosen according to the variant of the error
+    /// (see `Error::to_exception()`). `Error::Exception` re-raises the original exception object
+    /// with its backtrace.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ruru::result::Error;
+    /// use ruru::VM;
+    /// # VM::init();
+    ///
+    /// VM::raise_error(Error::KeyError {
+    ///     message: "Key not found: :port".to_string(),
+    /// });
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// raise KeyError, 'Key not found: :port'
+    /// ```
+    pub fn raise_error(error: Error) {
+        match error {
+            Error::Exception(exception, _) => vm::raise_exception(exception.value()),
+            error => vm::raise(error.to_exception().value(), &error.to_string()),
+        }
+    }
+
     /// Converts a block given to current method to a `Proc`
     ///
     /// It works similarly to `def method(&block)` which converts block to `Proc`
@@ -238,6 +426,421 @@ impl VM {
         unsafe { slice::from_raw_parts(arguments, argc as usize).to_vec() }
     }
 
+    /// Converts a pointer to array of `AnyObject`s to `Vec<AnyObject>` checking the pointer and
+    /// the number of arguments.
+    ///
+    /// Unlike `parse_arguments()`, an empty vector is returned when `argc` is not positive or
+    /// `arguments` is a null pointer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ptr;
+    ///
+    /// use ruru::{AnyObject, Fixnum, Object, VM};
+    /// # VM::init();
+    ///
+    /// let arguments = [Fixnum::new(1).to_any_object()];
+    ///
+    /// assert_eq!(VM::parse_arguments_checked(1, arguments.as_ptr()).len(), 1);
+    /// assert_eq!(VM::parse_arguments_checked(-1, arguments.as_ptr()).len(), 0);
+    /// assert_eq!(VM::parse_arguments_checked(1, ptr::null::<AnyObject>()).len(), 0);
+    /// ```
+    pub fn parse_arguments_checked(argc: Argc, arguments: *const AnyObject) -> Vec<AnyObject> {
+        if argc <= 0 || arguments.is_null() {
+            Vec::new()
+        } else {
+            Self::parse_arguments(argc, arguments)
+        }
+    }
+
+    /// Converts arguments like `parse_arguments_checked()` and returns `ArgumentError` if fewer
+    /// than `count` arguments are given.
+    ///
+    /// It can be used in callbacks written without macros to check the number of arguments
+    /// before accessing them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Fixnum, Object, VM};
+    /// # VM::init();
+    ///
+    /// let arguments = [Fixnum::new(1).to_any_object()];
+    ///
+    /// assert!(VM::parse_arguments_at_least(1, arguments.as_ptr(), 1).is_ok());
+    ///
+    /// let error = VM::parse_arguments_at_least(1, arguments.as_ptr(), 2).unwrap_err();
+    ///
+    /// assert_eq!(error.to_string(), "wrong number of arguments (given 1, expected at least 2)");
+    /// ```
+    pub fn parse_arguments_at_least(
+        argc: Argc,
+        arguments: *const AnyObject,
+        count: usize,
+    ) -> Result<Vec<AnyObject>, Error> {
+        let arguments = Self::parse_arguments_checked(argc, arguments);
+
+        if arguments.len() < count {
+            let message = format!(
+                "wrong number of arguments (given {}, expected at least {})",
+                arguments.len(),
+                count
+            );
+
+            Err(Error::ArgumentError(message))
+        } else {
+            Ok(arguments)
+        }
+    }
+
+    /// Extracts keyword arguments which are passed as a trailing `Hash`.
+    ///
+    /// Returns `ArgumentError` if unexpected keywords are given. Values are converted with
+    /// `KeywordArguments::get()`, `get_or()` and `get_required()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate ruru;
+    ///
+    /// use ruru::result::Error;
+    /// use ruru::{Array, Class, Fixnum, Hash, Object, RString, Symbol, VM};
+    ///
+    /// class!(Server);
+    ///
+    /// methods!(
+    ///     Server,
+    ///     itself,
+    ///
+    ///     fn address(*arguments: Array) -> Result<RString, Error> {
+    ///         let arguments: Vec<_> = arguments.into_iter().collect();
+    ///         let keywords = VM::parse_keyword_arguments(&arguments, &["host", "port"])?;
+    ///
+    ///         let host = keywords.get_or("host", RString::new("localhost"))?;
+    ///         let port = keywords.get_or("port", Fixnum::new(8080))?;
+    ///
+    ///         Ok(RString::new(&format!("{}:{}", host.to_str(), port.to_i64())))
+    ///     }
+    /// );
+    ///
+    /// fn main() {
+    ///     # VM::init();
+    ///     Class::new("Server", None).define(|itself| {
+    ///         itself.def("address", address);
+    ///     });
+    ///
+    ///     let server = Class::from_existing("Server").new_instance(None);
+    ///
+    ///     let mut options = Hash::new();
+    ///     options.store(Symbol::new("host"), RString::new("example.com"));
+    ///
+    ///     let address = server
+    ///         .protect_send("address", &[options.to_any_object()])
+    ///         .unwrap()
+    ///         .try_convert_to::<RString>()
+    ///         .unwrap();
+    ///
+    ///     assert_eq!(address.to_str(), "example.com:8080");
+    /// }
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// class Server
+    ///   def address(host: 'localhost', port: 8080)
+    ///     "#{host}:#{port}"
+    ///   end
+    /// end
+    /// ```
+    pub fn parse_keyword_arguments(
+        arguments: &[AnyObject],
+        keywords: &[&str],
+    ) -> Result<KeywordArguments, Error> {
+        KeywordArguments::parse(arguments, keywords)
+    }
+
+    /// Returns the version of Ruby as `(major, minor, teeny)`.
+    ///
+    /// The version is parsed from `RUBY_VERSION` once and cached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Class, Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let code = RString::new("RUBY_VERSION").to_any_object();
+    /// let ruby_version = Class::from_existing("Object")
+    ///     .protect_send("eval", &[code])
+    ///     .unwrap()
+    ///     .try_convert_to::<RString>()
+    ///     .unwrap();
+    ///
+    /// let (major, minor, teeny) = VM::ruby_version();
+    ///
+    /// assert!(major >= 2);
+    /// assert_eq!(format!("{}.{}.{}", major, minor, teeny), ruby_version.to_str());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// RUBY_VERSION.split('.').map(&:to_i)
+    /// ```
+    pub fn ruby_version() -> (u32, u32, u32) {
+        vm::ruby_version()
+    }
+
+    /// Returns the version of Ruby as a string, for example `"2.3.1"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::VM;
+    /// # VM::init();
+    ///
+    /// let major: u32 = VM::ruby_version_string().split('.').next().unwrap().parse().unwrap();
+    ///
+    /// assert!(major >= 2);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// RUBY_VERSION
+    /// ```
+    pub fn ruby_version_string() -> &'static str {
+        vm::ruby_version_string()
+    }
+
+    /// Checks whether the version of Ruby is at least `major.minor.teeny`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::VM;
+    /// # VM::init();
+    ///
+    /// assert!(VM::ruby_version_at_least(1, 9, 0));
+    /// assert!(!VM::ruby_version_at_least(100, 0, 0));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// Gem::Version.new(RUBY_VERSION) >= Gem::Version.new('1.9.0')
+    /// ```
+    pub fn ruby_version_at_least(major: u32, minor: u32, teeny: u32) -> bool {
+        vm::ruby_version_at_least(major, minor, teeny)
+    }
+
+    /// Starts garbage collection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Array, RString, VM};
+    /// # VM::init();
+    ///
+    /// VM::gc_disable();
+    ///
+    /// let mut array = Array::new();
+    ///
+    /// for _ in 0..1000 {
+    ///     array.push(RString::new("Hello, World!"));
+    /// }
+    ///
+    /// VM::gc_enable();
+    /// VM::gc_start();
+    ///
+    /// assert_eq!(array.length(), 1000);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// GC.disable
+    ///
+    /// array = []
+    ///
+    /// 1000.times do
+    ///   array << 'Hello, World!'
+    /// end
+    ///
+    /// GC.enable
+    /// GC.start
+    ///
+    /// array.length == 1000
+    /// ```
+    ///
+    /// Objects which are not referenced anymore are collected
+    ///
+    /// ```
+    /// use ruru::{Class, ObjectSpace, VM};
+    /// # VM::init();
+    ///
+    /// let class = Class::new("Temporary", None);
+    ///
+    /// VM::gc_disable();
+    ///
+    /// for _ in 0..1000 {
+    ///     class.new_instance(None);
+    /// }
+    ///
+    /// VM::gc_enable();
+    /// VM::gc_start();
+    ///
+    /// // The garbage collector is conservative, a few objects may still be referenced from
+    /// // the stack
+    /// assert!(ObjectSpace::each_object(Some(&class)).count() < 1000);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// class Temporary
+    /// end
+    ///
+    /// GC.disable
+    ///
+    /// 1000.times { Temporary.new }
+    ///
+    /// GC.enable
+    /// GC.start
+    ///
+    /// ObjectSpace.each_object(Temporary).count < 1000
+    /// ```
+    pub fn gc_start() {
+        gc::start();
+    }
+
+    /// Marks a raw `Value` as alive during garbage collection.
+    ///
+    /// It should be used in `mark` functions of structs wrapped with `wrappable_struct!` which
+    /// hold `Value`s, because the garbage collector does not see them. For `Object`s, `GC::mark()`
+    /// can be used as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use] extern crate ruru;
+    /// #[macro_use] extern crate lazy_static;
+    ///
+    /// use ruru::types::Value;
+    /// use ruru::{AnyObject, Class, Object, RString, VM};
+    ///
+    /// pub struct Cache {
+    ///     value: Value,
+    /// }
+    ///
+    /// wrappable_struct! {
+    ///     Cache,
+    ///     CacheWrapper,
+    ///     CACHE_WRAPPER,
+    ///
+    ///     mark(data) {
+    ///         VM::gc_mark(data.value);
+    ///     }
+    /// }
+    ///
+    /// class!(RubyCache);
+    ///
+    /// methods!(
+    ///     RubyCache,
+    ///     itself,
+    ///
+    ///     fn ruby_cache_value() -> AnyObject {
+    ///         AnyObject::from(itself.get_data(&*CACHE_WRAPPER).value)
+    ///     }
+    /// );
+    ///
+    /// fn main() {
+    ///     # VM::init();
+    ///     let data_class = Class::from_existing("Data");
+    ///
+    ///     let mut class = Class::new("RubyCache", Some(&data_class));
+    ///
+    ///     class.define(|itself| {
+    ///         itself.def("value", ruby_cache_value);
+    ///     });
+    ///
+    ///     let cache = Cache { value: RString::new(&"cached ".repeat(10)).value() };
+    ///     let cache: AnyObject = class.wrap_data(cache, &*CACHE_WRAPPER);
+    ///
+    ///     VM::gc_start();
+    ///
+    ///     let value = cache
+    ///         .protect_send("value", &[])
+    ///         .unwrap()
+    ///         .try_convert_to::<RString>()
+    ///         .unwrap();
+    ///
+    ///     assert_eq!(value.to_str(), "cached ".repeat(10));
+    /// }
+    /// ```
+    pub fn gc_mark(value: Value) {
+        gc::mark(value);
+    }
+
+    /// Disables garbage collection.
+    ///
+    /// Returns `true` if garbage collection was already disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::VM;
+    /// # VM::init();
+    ///
+    /// assert_eq!(VM::gc_disable(), false);
+    /// assert_eq!(VM::gc_disable(), true);
+    ///
+    /// VM::gc_enable();
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// GC.disable == false
+    /// GC.disable == true
+    ///
+    /// GC.enable
+    /// ```
+    pub fn gc_disable() -> bool {
+        gc::disable()
+    }
+
+    /// Enables garbage collection.
+    ///
+    /// Returns `true` if garbage collection was disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::VM;
+    /// # VM::init();
+    ///
+    /// VM::gc_disable();
+    ///
+    /// assert_eq!(VM::gc_enable(), true);
+    /// assert_eq!(VM::gc_enable(), false);
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// GC.disable
+    ///
+    /// GC.enable == true
+    /// GC.enable == false
+    /// ```
+    pub fn gc_enable() -> bool {
+        gc::enable()
+    }
+
     /// Release GVL for current thread.
     ///
     /// **Warning!** Due to MRI limitations, interaction with Ruby objects is not allowed while
@@ -315,4 +918,85 @@ impl VM {
     {
         vm::protect(func)
     }
+
+    /// Returns the exception which is currently being handled (Ruby `$!`).
+    ///
+    /// Returns `None` if there is no such exception. An exception caught by `VM::protect()` stays
+    /// available until it is cleared with `VM::clear_exception()`. Methods which return `Result`
+    /// (for example, `Object::protect_send()`) clear the exception before returning the error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Class, Object, VM};
+    /// # VM::init();
+    ///
+    /// assert!(VM::current_exception().is_none());
+    ///
+    /// let result = VM::protect(|| {
+    ///     VM::raise(Class::from_existing("ArgumentError"), "Wrong argument");
+    /// });
+    ///
+    /// let exception = VM::current_exception().unwrap();
+    ///
+    /// assert!(result.is_err());
+    /// assert_eq!(exception.class(), Class::from_existing("ArgumentError"));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// begin
+    ///   raise ArgumentError, 'Wrong argument'
+    /// rescue
+    ///   $!.class == ArgumentError
+    /// end
+    /// ```
+    pub fn current_exception() -> Option<AnyObject> {
+        let exception = AnyObject::from(vm::errinfo());
+
+        if exception.is_nil() {
+            None
+        } else {
+            Some(exception)
+        }
+    }
+
+    /// Clears the exception which is currently being handled (sets Ruby `$!` to `nil`).
+    ///
+    /// Call it after an exception caught by `VM::protect()` is handled, so it does not leak into
+    /// later calls to Ruby.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Class, VM};
+    /// # VM::init();
+    ///
+    /// let _ = VM::protect(|| {
+    ///     VM::raise(Class::from_existing("ArgumentError"), "Wrong argument");
+    /// });
+    ///
+    /// assert!(VM::current_exception().is_some());
+    ///
+    /// VM::clear_exception();
+    ///
+    /// assert!(VM::current_exception().is_none());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// begin
+    ///   raise ArgumentError, 'Wrong argument'
+    /// rescue
+    ///   $!.class == ArgumentError
+    /// end
+    ///
+    /// # `$!` is cleared when the exception is handled
+    /// $! == nil
+    /// ```
+    pub fn clear_exception() {
+        vm::set_errinfo(NilClass::new().value());
+    }
 }
@@ -0,0 +1,161 @@
+use std::convert::{From, TryFrom};
+use std::fmt;
+
+use binding::weak_ref;
+use result::Error;
+use types::Value;
+
+use {AnyObject, Object, VerifiedObject};
+
+/// `WeakRef`
+///
+/// A reference which does not prevent the referenced object from being garbage collected.
+///
+/// The `weakref` library is required automatically when the first `WeakRef` is created.
+#[derive(PartialEq)]
+pub struct WeakRef {
+    value: Value,
+}
+
+impl WeakRef {
+    /// Creates a new weak reference to the object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Object, RString, VM, WeakRef};
+    /// # VM::init();
+    ///
+    /// let string = RString::new("Hello");
+    /// let weak_ref = WeakRef::new(string.to_any_object());
+    ///
+    /// assert!(weak_ref.is_alive());
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// require 'weakref'
+    ///
+    /// weak_ref = WeakRef.new('Hello')
+    ///
+    /// weak_ref.weakref_alive? == true
+    /// ```
+    pub fn new(object: AnyObject) -> Self {
+        Self::from(weak_ref::new(object.value()))
+    }
+
+    /// Returns the referenced object or `None` if it has been garbage collected.
+    ///
+    /// The object is retrieved with protection (see `VM::protect()`), so the `WeakRef::RefError`
+    /// raised for collected objects is not propagated to Ruby.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::{Array, Object, RString, VM, WeakRef};
+    /// # VM::init();
+    ///
+    /// let string = RString::new("Hello");
+    /// let weak_ref = WeakRef::new(string.to_any_object());
+    ///
+    /// let object = weak_ref.get().unwrap();
+    ///
+    /// assert!(object.is_identical(&string));
+    ///
+    /// // Weak references are kept in a Ruby array, only the strings can be collected
+    /// let weak_refs: Array = (0..100)
+    ///     .map(|number| WeakRef::new(RString::new(&number.to_string()).to_any_object()))
+    ///     .map(|weak_ref| weak_ref.to_any_object())
+    ///     .collect();
+    ///
+    /// VM::gc_start();
+    ///
+    /// let collected: Vec<WeakRef> = weak_refs
+    ///     .into_iter()
+    ///     .map(|weak_ref| weak_ref.try_convert_to::<WeakRef>().unwrap())
+    ///     .filter(|weak_ref| weak_ref.get().is_none())
+    ///     .collect();
+    ///
+    /// assert!(!collected.is_empty());
+    /// assert!(collected.iter().all(|weak_ref| !weak_ref.is_alive()));
+    /// ```
+    ///
+    /// Ruby:
+    ///
+    /// ```ruby
+    /// require 'weakref'
+    ///
+    /// string = 'Hello'
+    /// weak_ref = WeakRef.new(string)
+    ///
+    /// object =
+    ///   begin
+    ///     weak_ref.__getobj__
+    ///   rescue WeakRef::RefError
+    ///     nil
+    ///   end
+    ///
+    /// object.equal?(string) == true
+    ///
+    /// weak_refs = 100.times.map { |number| WeakRef.new(number.to_s) }
+    ///
+    /// GC.start
+    ///
+    /// collected = weak_refs.reject(&:weakref_alive?)
+    /// collected.any? == true
+    /// ```
+    pub fn get(&self) -> Option<AnyObject> {
+        weak_ref::get(self.value()).ok().map(AnyObject::from)
+    }
+
+    /// Checks whether the referenced object has not been garbage collected yet.
+    ///
+    /// See the example for `new()`.
+    pub fn is_alive(&self) -> bool {
+        weak_ref::is_alive(self.value())
+    }
+}
+
+impl From<Value> for WeakRef {
+    fn from(value: Value) -> Self {
+        WeakRef { value: value }
+    }
+}
+
+impl Object for WeakRef {
+    #[inline]
+    fn value(&self) -> Value {
+        self.value
+    }
+}
+
+impl fmt::Debug for WeakRef {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.inspect())
+    }
+}
+
+impl VerifiedObject for WeakRef {
+    fn is_correct_type<T: Object>(object: &T) -> bool {
+        weak_ref::is_weak_ref(object.value())
+    }
+
+    fn error_message() -> &'static str {
+        "Error converting to WeakRef"
+    }
+}
+
+impl TryFrom<AnyObject> for WeakRef {
+    type Error = Error;
+
+    fn try_from(object: AnyObject) -> Result<Self, Error> {
+        object.try_convert_to::<WeakRef>()
+    }
+}
+
+impl From<WeakRef> for AnyObject {
+    fn from(object: WeakRef) -> Self {
+        object.to_any_object()
+    }
+}
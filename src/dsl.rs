@@ -3,6 +3,9 @@
 /// This macro does not define an actual Ruby class. It only creates structs for using
 /// the class in Rust. To define the class in Ruby, use `Class` structure.
 ///
+/// To derive other traits for the struct or to add fields to it, write the struct by hand and
+/// use `#[derive(RuruObject)]` (requires the `derive` feature).
+///
 /// # Examples
 ///
 /// ```
@@ -58,10 +61,111 @@
 ///   end
 /// end
 /// ```
+///
+/// # Subclasses
+///
+/// `class!(Class < Superclass)` additionally implements `VerifiedObject` for the struct, so
+/// objects can be converted to it with `try_convert_to()`. The check uses `is_a?` with the Ruby
+/// class which is looked up by name when an object is converted. As with the simple form, the
+/// Ruby class itself must be defined with `Class::new(name, Some(&superclass))`. Conversion fails
+/// if the class is not defined yet or if it is not a subclass of `Superclass`.
+///
+/// ```
+/// #[macro_use]
+/// extern crate ruru;
+///
+/// use ruru::{Class, NilClass, Object, RString, VM};
+///
+/// class!(ValidationError < StandardError);
+/// class!(UndefinedError < StandardError);
+/// class!(Validator);
+///
+/// methods!(
+///     Validator,
+///     itself,
+///
+///     fn validate() -> NilClass {
+///         VM::raise(Class::from_existing("ValidationError"), "Name is missing");
+///
+///         NilClass::new()
+///     }
+/// );
+///
+/// fn main() {
+///     # VM::init();
+///     let standard_error = Class::from_existing("StandardError");
+///
+///     Class::new("ValidationError", Some(&standard_error));
+///
+///     Class::new("Validator", None).define(|itself| {
+///         itself.def("validate", validate);
+///     });
+///
+///     let code = "begin; Validator.new.validate; rescue ValidationError => error; error; end";
+///     let code = RString::new(code);
+///
+///     let error = Class::from_existing("Object")
+///         .protect_send("eval", &[code.to_any_object()])
+///         .unwrap()
+///         .try_convert_to::<ValidationError>()
+///         .unwrap();
+///
+///     let message = error
+///         .protect_send("message", &[])
+///         .unwrap()
+///         .try_convert_to::<RString>()
+///         .unwrap();
+///
+///     assert_eq!(message.to_str(), "Name is missing");
+///     assert!(message.to_any_object().try_convert_to::<ValidationError>().is_err());
+///     assert!(error.to_any_object().try_convert_to::<UndefinedError>().is_err());
+/// }
+/// ```
+///
+/// Ruby:
+///
+/// ```ruby
+/// class ValidationError < StandardError
+/// end
+///
+/// class Validator
+///   def validate
+///     raise ValidationError, 'Name is missing'
+///   end
+/// end
+///
+/// begin
+///   Validator.new.validate
+/// rescue ValidationError => error
+///   error.message == 'Name is missing'
+/// end
+/// ```
 #[macro_export]
 macro_rules! class {
+    ($class: ident < $superclass: ident) => {
+        $crate::class!($class);
+
+        impl $crate::VerifiedObject for $class {
+            fn is_correct_type<T: $crate::Object>(object: &T) -> bool {
+                let class = $crate::Class::try_from_existing(stringify!($class));
+                let superclass = $crate::Class::try_from_existing(stringify!($superclass));
+
+                match (class, superclass) {
+                    (Ok(class), Ok(superclass)) => {
+                        class.is_subclass_of(&superclass) && object.is_a(&class)
+                    }
+                    _ => false,
+                }
+            }
+
+            fn error_message() -> &'static str {
+                concat!("Error converting to ", stringify!($class))
+            }
+        }
+    };
+
     ($class: ident) => {
-        #[derive(Debug, PartialEq)]
+        #[derive(PartialEq)]
         pub struct $class {
             value: $crate::types::Value,
         }
@@ -78,29 +182,41 @@ macro_rules! class {
                 self.value
             }
         }
+
+        impl ::std::fmt::Debug for $class {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                f.write_str(&$crate::Object::inspect(self))
+            }
+        }
+
+        impl From<$class> for $crate::AnyObject {
+            fn from(object: $class) -> Self {
+                $crate::Object::to_any_object(&object)
+            }
+        }
     }
 }
 
 /// Creates unsafe callbacks for Ruby methods
 ///
-/// This macro is unsafe, because:
+/// This macro is unsafe, because it uses automatic unsafe conversions for arguments (no
+/// guarantee that Ruby objects match the types which you expect).
 ///
-///  - it uses automatic unsafe conversions for arguments
-///     (no guarantee that Ruby objects match the types which you expect);
-///  - no bound checks for the array of provided arguments
-///     (no guarantee that all the expected arguments are provided);
-///
-/// That is why creating callbacks in unsafe way may cause panics.
+/// That is why creating callbacks in unsafe way may cause undefined behaviour.
 ///
 /// Due to the same reasons unsafe callbacks are faster.
 ///
+/// `ArgumentError` is raised if fewer arguments than expected are provided.
+///
 /// Use it when:
 ///
 ///  - you own the Ruby code which passes arguments to callback;
 ///  - you are sure that all the object has correct type;
-///  - you are sure that all the required arguments are provided;
 ///  - Ruby code has a good test coverage.
 ///
+/// Callbacks written without macros can check the number of arguments with
+/// `VM::parse_arguments_at_least()` as a middle ground between the two macros.
+///
 /// # Examples
 ///
 /// ```
@@ -142,6 +258,59 @@ macro_rules! class {
 ///   end
 /// end
 /// ```
+///
+/// # Partial safety
+///
+/// Arguments of unknown type can be declared as `AnyObject` and checked in the method body with
+/// `AnyObject::downcast()`, which is cheaper than `try_convert_to()` when a type mismatch is
+/// expected.
+///
+/// ```
+/// #[macro_use]
+/// extern crate ruru;
+///
+/// use ruru::{AnyObject, Class, Fixnum, Object, RString, VM};
+///
+/// unsafe_methods!(
+///     RString,
+///     itself,
+///
+///     fn string_repeat(count: AnyObject) -> RString {
+///         let count = count.downcast::<Fixnum>().map_or(1, |count| count.to_i64());
+///
+///         RString::new(&itself.to_str().repeat(count as usize))
+///     }
+/// );
+///
+/// fn main() {
+///     # VM::init();
+///     Class::from_existing("String").define(|itself| {
+///         itself.def("repeat", string_repeat);
+///     });
+///
+///     let string = RString::new("ab");
+///
+///     let repeated = string.protect_send("repeat", &[Fixnum::new(2).to_any_object()]).unwrap();
+///     let not_repeated = string
+///         .protect_send("repeat", &[RString::new("2").to_any_object()])
+///         .unwrap();
+///
+///     assert_eq!(repeated.try_convert_to::<RString>().unwrap().to_str(), "abab");
+///     assert_eq!(not_repeated.try_convert_to::<RString>().unwrap().to_str(), "ab");
+/// }
+/// ```
+///
+/// Ruby:
+///
+/// ```ruby
+/// class String
+///   def repeat(count)
+///     count = 1 unless count.is_a?(Integer)
+///
+///     self * count
+///   end
+/// end
+/// ```
 #[macro_export]
 macro_rules! unsafe_methods {
     (
@@ -156,8 +325,25 @@ macro_rules! unsafe_methods {
             #[allow(unused_mut)]
             pub extern fn $method_name(argc: $crate::types::Argc,
                                        argv: *const $crate::AnyObject,
-                                       mut $itself_name: $itself_class) -> $return_type {
-                let _arguments = $crate::VM::parse_arguments(argc, argv);
+                                       _itself: $crate::AnyObject) -> $crate::AnyObject {
+                // The receiver and the result are passed as bare `VALUE`s, so structs with extra
+                // fields are converted from and to `AnyObject`
+                let mut $itself_name: $itself_class =
+                    ::std::convert::From::from($crate::Object::value(&_itself));
+
+                let _names: &[&str] = &[$(stringify!($arg_name)),*];
+                let _arguments = $crate::VM::parse_arguments_at_least(argc, argv, _names.len());
+
+                let _arguments = match _arguments {
+                    Ok(arguments) => arguments,
+                    Err(error) => {
+                        $crate::VM::raise_error(error);
+
+                        // `VM::raise()` does not return, the control is passed back to Ruby
+                        unreachable!()
+                    }
+                };
+
                 let mut _i = 0;
 
                 $(
@@ -169,7 +355,9 @@ macro_rules! unsafe_methods {
                     _i += 1;
                 )*
 
-                $body
+                let result = (|| -> $return_type { $body })();
+
+                $crate::Object::to_any_object(&result)
             }
         )*
     }
@@ -210,7 +398,8 @@ macro_rules! unsafe_methods {
 /// }
 /// ```
 ///
-/// You want to extract port from it. Default port is `8080` in case when:
+/// You want to extract port from it and store it in `@port`. Default port is `Server::DEFAULT_PORT`
+/// in case when:
 ///
 ///  - `address` is not a `Hash`
 ///  - `address[:port]` is not present
@@ -220,7 +409,7 @@ macro_rules! unsafe_methods {
 /// #[macro_use]
 /// extern crate ruru;
 ///
-/// use ruru::{Class, Fixnum, Hash, NilClass, Object, Symbol, VM};
+/// use ruru::{Class, Fixnum, Hash, NilClass, Object, RString, Symbol, VM};
 ///
 /// class!(Server);
 ///
@@ -229,14 +418,19 @@ macro_rules! unsafe_methods {
 ///     itself,
 ///
 ///     fn start(address: Hash) -> NilClass {
-///         let default_port = 8080;
+///         let default_port = itself
+///             .class()
+///             .const_get("DEFAULT_PORT")
+///             .and_then(|port| port.try_convert_to::<Fixnum>())
+///             .unwrap();
 ///
 ///         let port = address
 ///             .map(|hash| hash.at(&Symbol::new("port")))
 ///             .and_then(|port| port.try_convert_to::<Fixnum>())
-///             .map(|port| port.to_i64())
 ///             .unwrap_or(default_port);
 ///
+///         itself.instance_variable_set("@port", port);
+///
 ///         // Start server...
 ///
 ///         NilClass::new()
@@ -246,8 +440,23 @@ macro_rules! unsafe_methods {
 /// fn main() {
 ///     # VM::init();
 ///     Class::new("Server", None).define(|itself| {
+///         itself.const_set("DEFAULT_PORT", &Fixnum::new(8080));
+///         itself.attr_reader(&["port"]);
+///
 ///         itself.def("start", start);
 ///     });
+///
+///     let port = |code: &str| {
+///         let code = RString::new(code).to_any_object();
+///
+///         Class::from_existing("Object")
+///             .protect_send("eval", &[code])
+///             .unwrap()
+///             .try_convert_to::<Fixnum>()
+///     };
+///
+///     assert_eq!(port("Server.new.tap { |s| s.start(port: 3000) }.port"), Ok(Fixnum::new(3000)));
+///     assert_eq!(port("Server.new.tap { |s| s.start(nil) }.port"), Ok(Fixnum::new(8080)));
 /// }
 /// ```
 ///
@@ -255,20 +464,424 @@ macro_rules! unsafe_methods {
 ///
 /// ```ruby
 /// class Server
-///   def start(address)
-///     default_port = 8080
+///   DEFAULT_PORT = 8080
 ///
-///     port =
+///   attr_reader :port
+///
+///   def start(address)
+///     @port =
 ///       if address.is_a?(Hash) && address[:port].is_a?(Fixnum)
 ///         address[:port]
 ///       else
-///         default_port
+///         DEFAULT_PORT
 ///       end
 ///
 ///     # Start server...
 ///   end
 /// end
 /// ```
+///
+/// # Optional arguments
+///
+/// Arguments declared as `Option<T>` are optional. When such argument is not given (or is `nil`),
+/// it is `Ok(None)` instead of an `ArgumentError`.
+///
+/// ```
+/// #[macro_use]
+/// extern crate ruru;
+///
+/// use ruru::{Class, Object, RString, VM};
+///
+/// class!(Greeter);
+///
+/// methods!(
+///     Greeter,
+///     itself,
+///
+///     fn greet(name: Option<RString>) -> RString {
+///         let name = name
+///             .ok()
+///             .and_then(|name| name)
+///             .map(|name| name.to_string())
+///             .unwrap_or_else(|| "stranger".to_string());
+///
+///         RString::new(&format!("Hello, {}!", name))
+///     }
+/// );
+///
+/// fn main() {
+///     # VM::init();
+///     Class::new("Greeter", None).define(|itself| {
+///         itself.def("greet", greet);
+///     });
+///
+///     let greeter = Class::from_existing("Greeter").new_instance(None);
+///
+///     let with_name = greeter
+///         .protect_send("greet", &[RString::new("Alice").to_any_object()])
+///         .unwrap()
+///         .try_convert_to::<RString>()
+///         .unwrap();
+///
+///     let without_name = greeter
+///         .protect_send("greet", &[])
+///         .unwrap()
+///         .try_convert_to::<RString>()
+///         .unwrap();
+///
+///     assert_eq!(with_name.to_str(), "Hello, Alice!");
+///     assert_eq!(without_name.to_str(), "Hello, stranger!");
+/// }
+/// ```
+///
+/// Ruby:
+///
+/// ```ruby
+/// class Greeter
+///   def greet(name = nil)
+///     "Hello, #{name || 'stranger'}!"
+///   end
+/// end
+/// ```
+///
+/// # Variadic arguments
+///
+/// The last argument can be declared as `*name: Array` to capture all the remaining arguments,
+/// like `*args` in Ruby. Unlike other arguments, it has type `Array`, because it can not fail.
+///
+/// ```
+/// #[macro_use]
+/// extern crate ruru;
+///
+/// use ruru::{Array, Class, Fixnum, Object, VM};
+///
+/// class!(Calculator);
+///
+/// methods!(
+///     Calculator,
+///     itself,
+///
+///     fn sum(first: Fixnum, *rest: Array) -> Fixnum {
+///         let rest = rest
+///             .into_iter()
+///             .map(|number| number.try_convert_to::<Fixnum>().unwrap().to_i64())
+///             .sum::<i64>();
+///
+///         Fixnum::new(first.unwrap().to_i64() + rest)
+///     }
+/// );
+///
+/// fn main() {
+///     # VM::init();
+///     Class::new("Calculator", None).define(|itself| {
+///         itself.def("sum", sum);
+///     });
+///
+///     let calculator = Class::from_existing("Calculator").new_instance(None);
+///
+///     let sum_of = |numbers: &[i64]| {
+///         let numbers: Vec<_> = numbers
+///             .iter()
+///             .map(|&number| Fixnum::new(number).to_any_object())
+///             .collect();
+///
+///         calculator
+///             .protect_send("sum", &numbers)
+///             .unwrap()
+///             .try_convert_to::<Fixnum>()
+///             .unwrap()
+///             .to_i64()
+///     };
+///
+///     assert_eq!(sum_of(&[1]), 1);
+///     assert_eq!(sum_of(&[1, 2]), 3);
+///     assert_eq!(sum_of(&[1, 2, 3, 4]), 10);
+/// }
+/// ```
+///
+/// Ruby:
+///
+/// ```ruby
+/// class Calculator
+///   def sum(first, *rest)
+///     first + rest.sum
+///   end
+/// end
+/// ```
+///
+/// # Returning errors
+///
+/// Methods can return `Result<T, Error>` where `T` is a Ruby type. When the result is `Err`, the
+/// error is raised as a Ruby exception of the corresponding class (see `Error::to_exception()`).
+///
+/// Since arguments have type `Result<Object, Error>`, the `?` operator can be used to raise an
+/// exception when an argument is missing or has a wrong type.
+///
+/// ```
+/// #[macro_use]
+/// extern crate ruru;
+///
+/// use ruru::result::Error;
+/// use ruru::{Class, Fixnum, Object, RString, VM};
+///
+/// class!(Calculator);
+///
+/// methods!(
+///     Calculator,
+///     itself,
+///
+///     fn divide(a: Fixnum, b: Fixnum) -> Result<Fixnum, Error> {
+///         let a = a?.to_i64();
+///         let b = b?.to_i64();
+///
+///         if b == 0 {
+///             return Err(Error::RuntimeError("Division by zero".to_string()));
+///         }
+///
+///         Ok(Fixnum::new(a / b))
+///     }
+/// );
+///
+/// fn main() {
+///     # VM::init();
+///     Class::new("Calculator", None).define(|itself| {
+///         itself.def("divide", divide);
+///     });
+///
+///     let calculator = Class::from_existing("Calculator").new_instance(None);
+///
+///     let six = Fixnum::new(6).to_any_object();
+///     let three = Fixnum::new(3).to_any_object();
+///     let zero = Fixnum::new(0).to_any_object();
+///     let string = RString::new("3").to_any_object();
+///
+///     let result = calculator
+///         .protect_send("divide", &[six.clone(), three])
+///         .and_then(|result| result.try_convert_to::<Fixnum>());
+///
+///     assert_eq!(result, Ok(Fixnum::new(2)));
+///
+///     let missing_argument = calculator.protect_send("divide", &[six.clone()]).unwrap_err();
+///     let wrong_type = calculator.protect_send("divide", &[six.clone(), string]).unwrap_err();
+///     let division_by_zero = calculator.protect_send("divide", &[six, zero]).unwrap_err();
+///
+///     assert_eq!(missing_argument.to_exception(), Class::from_existing("ArgumentError"));
+///     assert_eq!(wrong_type.to_exception(), Class::from_existing("TypeError"));
+///     assert_eq!(
+///         wrong_type.to_string(),
+///         "Error converting to Fixnum for argument 'b' of method 'divide' (given String)"
+///     );
+///     assert_eq!(division_by_zero.to_exception(), Class::from_existing("RuntimeError"));
+///     assert_eq!(division_by_zero.to_string(), "Division by zero");
+/// }
+/// ```
+///
+/// Ruby:
+///
+/// ```ruby
+/// class Calculator
+///   def divide(a, b)
+///     raise TypeError unless a.is_a?(Fixnum) && b.is_a?(Fixnum)
+///     raise 'Division by zero' if b == 0
+///
+///     a / b
+///   end
+/// end
+/// ```
+///
+/// Objects of unknown type (`AnyObject`) can be converted with `TryInto`, which also works with
+/// the `?` operator. Converting back to `AnyObject` is done with `Into`.
+///
+/// ```
+/// #[macro_use]
+/// extern crate ruru;
+///
+/// use std::convert::TryInto;
+///
+/// use ruru::result::Error;
+/// use ruru::{AnyObject, Class, Fixnum, Hash, Object, Symbol, VM};
+///
+/// class!(Server);
+///
+/// methods!(
+///     Server,
+///     itself,
+///
+///     fn port(address: Hash) -> Result<Fixnum, Error> {
+///         let port: Fixnum = address?.at(&Symbol::new("port")).try_into()?;
+///
+///         Ok(port)
+///     }
+/// );
+///
+/// fn main() {
+///     # VM::init();
+///     Class::new("Server", None).define(|itself| {
+///         itself.def("port", port);
+///     });
+///
+///     let server = Class::from_existing("Server").new_instance(None);
+///
+///     let mut address = Hash::new();
+///     address.store(Symbol::new("port"), Fixnum::new(8080));
+///
+///     let address: AnyObject = address.into();
+///     let result = server
+///         .protect_send("port", &[address])
+///         .and_then(|port| port.try_into());
+///
+///     assert_eq!(result, Ok(Fixnum::new(8080)));
+///
+///     let wrong_address: AnyObject = Hash::new().into();
+///     let error = server.protect_send("port", &[wrong_address]).unwrap_err();
+///
+///     assert_eq!(error.to_exception(), Class::from_existing("TypeError"));
+/// }
+/// ```
+///
+/// Ruby:
+///
+/// ```ruby
+/// class Server
+///   def port(address)
+///     port = address[:port]
+///
+///     raise TypeError unless port.is_a?(Fixnum)
+///
+///     port
+///   end
+/// end
+/// ```
+///
+/// The same works for conversions which can fail with other errors. For example,
+/// `Fixnum::try_from_any()` returns `RangeError` for integers which do not fit into `i64`.
+///
+/// ```
+/// #[macro_use]
+/// extern crate ruru;
+///
+/// use ruru::result::Error;
+/// use ruru::{AnyObject, Class, Fixnum, Object, VM};
+///
+/// class!(Timer);
+///
+/// methods!(
+///     Timer,
+///     itself,
+///
+///     fn sleep_for(seconds: AnyObject) -> Result<Fixnum, Error> {
+///         let seconds = Fixnum::try_from_any(&seconds?)?;
+///
+///         Ok(seconds)
+///     }
+/// );
+///
+/// fn main() {
+///     # VM::init();
+///     Class::new("Timer", None).define(|itself| {
+///         itself.def("sleep_for", sleep_for);
+///     });
+///
+///     let timer = Class::from_existing("Timer").new_instance(None);
+///
+///     let one = Fixnum::new(1).to_any_object();
+///     let huge_number = Fixnum::new(i64::max_value()).protect_send("+", &[one]).unwrap();
+///
+///     let error = timer.protect_send("sleep_for", &[huge_number]).unwrap_err();
+///
+///     assert_eq!(error.to_exception(), Class::from_existing("RangeError"));
+/// }
+/// ```
+///
+/// Ruby:
+///
+/// ```ruby
+/// class Timer
+///   def sleep_for(seconds)
+///     raise TypeError unless seconds.is_a?(Integer)
+///     raise RangeError unless seconds.between?(-2**63, 2**63 - 1)
+///
+///     seconds
+///   end
+/// end
+/// ```
+///
+/// # Panics
+///
+/// A panic inside of a method body does not unwind into Ruby. It is caught and raised as
+/// `RuntimeError` with the panic message.
+///
+/// ```
+/// #[macro_use]
+/// extern crate ruru;
+///
+/// use ruru::{Class, Fixnum, Object, VM};
+///
+/// class!(Parser);
+///
+/// methods!(
+///     Parser,
+///     itself,
+///
+///     fn parse() -> Fixnum {
+///         panic!("Unexpected end of input");
+///     }
+/// );
+///
+/// fn main() {
+///     # VM::init();
+///     Class::new("Parser", None).define(|itself| {
+///         itself.def("parse", parse);
+///     });
+///
+///     let parser = Class::from_existing("Parser").new_instance(None);
+///     let error = parser.protect_send("parse", &[]).unwrap_err();
+///
+///     assert_eq!(error.to_exception(), Class::from_existing("RuntimeError"));
+///     assert_eq!(error.to_string(), "Unexpected end of input");
+/// }
+/// ```
+///
+/// Ruby:
+///
+/// ```ruby
+/// class Parser
+///   def parse
+///     raise 'Unexpected end of input'
+///   end
+/// end
+/// ```
+///
+/// # Importing
+///
+/// The macro can be imported by name without `#[macro_use]`.
+///
+/// ```
+/// extern crate ruru;
+///
+/// use ruru::methods;
+/// use ruru::{Class, Fixnum, Object, VM};
+///
+/// methods!(
+///     Fixnum,
+///     itself,
+///
+///     fn fixnum_double() -> Fixnum {
+///         Fixnum::new(itself.to_i64() * 2)
+///     }
+/// );
+///
+/// fn main() {
+///     # VM::init();
+///     Class::from_existing("Integer").define(|itself| {
+///         itself.def("double", fixnum_double);
+///     });
+///
+///     let result = Fixnum::new(2).protect_send("double", &[]).unwrap();
+///
+///     assert_eq!(result.try_convert_to::<Fixnum>(), Ok(Fixnum::new(4)));
+/// }
+/// ```
 #[macro_export]
 macro_rules! methods {
     (
@@ -276,44 +889,111 @@ macro_rules! methods {
         $itself_name: ident,
         $(
             fn $method_name: ident
-            ($($arg_name: ident: $arg_type: ty),*) -> $return_type: ident $body: block
+            ($($arguments: tt)*) -> $return_type: ty $body: block
         )*
     ) => {
         $(
             #[allow(unused_mut)]
             pub extern fn $method_name(argc: $crate::types::Argc,
                                        argv: *const $crate::AnyObject,
-                                       mut $itself_name: $itself_class) -> $return_type {
-                let _arguments = $crate::VM::parse_arguments(argc, argv);
-                let mut _i = 0;
+                                       _itself: $crate::AnyObject) -> $crate::AnyObject {
+                // The receiver is passed as a bare `VALUE`, so structs with extra fields are
+                // converted from `AnyObject`
+                let mut $itself_name: $itself_class =
+                    ::std::convert::From::from($crate::Object::value(&_itself));
 
-                $(
-                    let $arg_name =
-                        _arguments
-                            .get(_i)
-                            .ok_or_else(|| {
-                                $crate::result::Error::ArgumentError(
-                                    format!(
-                                        "Argument '{}: {}' not found for method '{}'",
-                                        stringify!($arg_name),
-                                        stringify!($arg_type),
-                                        stringify!($method_name)
-                                    )
-                                )
-                            }).and_then(|argument| {
-                                <$crate::AnyObject as $crate::Object>
-                                    ::try_convert_to::<$arg_type>(argument)
-                            });
+                let _arguments = $crate::VM::parse_arguments_checked(argc, argv);
 
-                    _i += 1;
-                )*
+                $crate::ruru_method_arguments!($method_name, _arguments, 0; $($arguments)*);
 
-                $body
+                let result = ::std::panic::catch_unwind(
+                    ::std::panic::AssertUnwindSafe(|| -> $return_type { $body })
+                );
+
+                match result {
+                    Ok(result) => $crate::result::ReturnValue::into_return_value(result),
+                    Err(payload) => {
+                        let error = $crate::result::Error::from_panic(payload);
+
+                        $crate::VM::raise_error(error);
+
+                        // `VM::raise()` does not return, the control is passed back to Ruby
+                        unreachable!()
+                    }
+                }
             }
         )*
     }
 }
 
+// Converts arguments of a method defined with `methods!` one by one. `$index` is the position
+// of the first unprocessed argument.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! ruru_method_arguments {
+    ($method_name: ident, $arguments: ident, $index: expr;) => {};
+
+    (
+        $method_name: ident, $arguments: ident, $index: expr;
+        *$splat_name: ident: $splat_type: ty
+    ) => {
+        let $splat_name: $splat_type = {
+            let mut rest = $crate::Array::new();
+
+            if $index < $arguments.len() {
+                rest.push_all(&$arguments[$index..]);
+            }
+
+            rest
+        };
+    };
+
+    (
+        $method_name: ident, $arguments: ident, $index: expr;
+        $arg_name: ident: $arg_type: ty, $($rest: tt)*
+    ) => {
+        $crate::ruru_method_arguments!($method_name, $arguments, $index; $arg_name: $arg_type);
+        $crate::ruru_method_arguments!($method_name, $arguments, $index + 1; $($rest)*);
+    };
+
+    (
+        $method_name: ident, $arguments: ident, $index: expr;
+        $arg_name: ident: $arg_type: ty
+    ) => {
+        let $arg_name = match $arguments.get($index) {
+            Some(argument) => {
+                <$arg_type as $crate::result::MethodArgument>::convert(argument)
+                    .map_err(|error| {
+                        let class = <$crate::AnyObject as $crate::Object>::class(argument);
+
+                        $crate::result::Error::TypeError(
+                            format!(
+                                "{} for argument '{}' of method '{}' (given {})",
+                                error,
+                                stringify!($arg_name),
+                                stringify!($method_name),
+                                class.name()
+                            )
+                        )
+                    })
+            },
+            None => {
+                <$arg_type as $crate::result::MethodArgument>::missing()
+                    .ok_or_else(|| {
+                        $crate::result::Error::ArgumentError(
+                            format!(
+                                "Argument '{}: {}' not found for method '{}'",
+                                stringify!($arg_name),
+                                stringify!($arg_type),
+                                stringify!($method_name)
+                            )
+                        )
+                    })
+            },
+        };
+    };
+}
+
 /// Makes a Rust struct wrappable for Ruby objects.
 ///
 /// **Note:** Currently to be able to use `wrappable_struct!` macro, you should include
@@ -621,7 +1301,7 @@ macro_rules! wrappable_struct {
                 let name = $crate::util::str_to_cstring(name);
                 let reserved_bytes: [*mut $crate::types::c_void; 2] = [::std::ptr::null_mut(); 2];
 
-                let dmark = wrappable_struct!(@mark_function_pointer $($tail)*);
+                let dmark = $crate::wrappable_struct!(@mark_function_pointer $($tail)*);
 
                 let data_type = $crate::types::DataType {
                     wrap_struct_name: name.into_raw(),
@@ -643,7 +1323,7 @@ macro_rules! wrappable_struct {
                 }
             }
 
-            wrappable_struct!(@mark_function_definition $struct_name $($tail)*);
+            $crate::wrappable_struct!(@mark_function_definition $struct_name $($tail)*);
         }
 
         unsafe impl<T> Sync for $wrapper<T> {}
@@ -4,6 +4,9 @@ extern crate lazy_static;
 
 extern crate ruby_sys;
 
+#[cfg(feature = "derive")]
+extern crate ruru_derive;
+
 mod binding;
 mod class;
 
@@ -15,24 +18,107 @@ pub mod typed_data;
 pub mod types;
 pub mod util;
 
-pub use class::any_object::AnyObject;
+pub use class::any_object::{AnyObject, DigSegment};
 pub use class::array::Array;
 pub use class::boolean::Boolean;
-pub use class::class::Class;
+pub use class::class::{Class, Visibility};
+pub use class::class_builder::ClassBuilder;
+pub use class::encoding::Encoding;
+pub use class::enumerator::Enumerator;
+pub use class::fiber::Fiber;
 pub use class::fixnum::Fixnum;
 pub use class::float::Float;
-pub use class::gc::GC;
+pub use class::gc::{GcGuard, GC};
 pub use class::hash::Hash;
 pub use class::integer::Integer;
+pub use class::keyword_arguments::KeywordArguments;
+pub use class::method::Method;
+pub use class::module::Module;
+pub use class::mutex::Mutex;
 pub use class::nil_class::NilClass;
+pub use class::object_space::ObjectSpace;
+pub use class::queue::Queue;
 pub use class::rproc::Proc;
 pub use class::string::RString;
 pub use class::symbol::Symbol;
 pub use class::thread::Thread;
+pub use class::unbound_method::UnboundMethod;
 pub use class::vm::VM;
+pub use class::weak_ref::WeakRef;
 
 pub use class::traits::object::Object;
 pub use class::traits::verified_object::VerifiedObject;
 
+/// Derives the same code as `class!` macro for a hand-written struct
+///
+/// Available with the `derive` feature. The struct must have a field named `value` of type
+/// `Value`, other fields are initialized with `Default::default()` when the struct is created
+/// from a `Value`.
+///
+/// Ruby objects only carry the `value`, so other fields are not preserved when the object is
+/// passed to Ruby and back. For example, the receiver of a method defined with `methods!` is
+/// created with `From<Value>`.
+///
+/// `VerifiedObject` is implemented as well, objects are checked with `is_a?` against the Ruby
+/// class with the same name as the struct.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use]
+/// extern crate ruru;
+///
+/// use ruru::{Class, Object, RString, RuruObject, VM};
+/// use ruru::types::Value;
+///
+/// #[derive(Clone, Debug, PartialEq, RuruObject)]
+/// pub struct Greeter {
+///     value: Value,
+///     name: Option<String>,
+/// }
+///
+/// impl Greeter {
+///     fn greet(&self) -> String {
+///         format!("Hello, {}!", self.name.as_ref().map_or("World", String::as_str))
+///     }
+/// }
+///
+/// methods!(
+///     Greeter,
+///     itself,
+///
+///     fn greeting() -> RString {
+///         RString::new(&itself.greet())
+///     }
+/// );
+///
+/// fn main() {
+///     # VM::init();
+///     Class::new("Greeter", None).define(|itself| {
+///         itself.def("greeting", greeting);
+///     });
+///
+///     let value = Class::from_existing("Greeter").new_instance(None).value();
+///
+///     let greeter = Greeter { value: value, name: Some("Ruby".to_string()) };
+///     let copy = greeter.clone();
+///
+///     let greeting = copy
+///         .protect_send("greeting", &[])
+///         .unwrap()
+///         .try_convert_to::<RString>()
+///         .unwrap();
+///
+///     assert_eq!(copy, greeter);
+///     assert_eq!(greeter.greet(), "Hello, Ruby!");
+///     assert_eq!(greeting.to_str(), "Hello, World!");
+///
+///     assert!(greeter.to_any_object().try_convert_to::<Greeter>().is_ok());
+///     assert!(RString::new("Greeter").to_any_object().try_convert_to::<Greeter>().is_err());
+/// }
+/// ```
+#[cfg(feature = "derive")]
+pub use ruru_derive::RuruObject;
+
 #[test]
 fn it_works() {}
@@ -1,8 +1,12 @@
+use std::any::Any;
 use std::error;
 use std::fmt::{self, Display, Formatter};
 use std::result;
 
-use Class;
+use binding::util as binding_util;
+use binding::vm;
+
+use {AnyObject, Class, Fixnum, Object, RString, VerifiedObject, VM};
 
 pub type Result<T> = result::Result<T, Error>;
 
@@ -10,9 +14,96 @@ pub type Result<T> = result::Result<T, Error>;
 pub enum Error {
     ArgumentError(String),
     TypeError(String),
+    RuntimeError(String),
+    NoMethodError { message: String },
+    NameError { message: String },
+    KeyError { message: String },
+    IndexError { message: String },
+    RangeError(String),
+    StopIteration { message: String },
+    NotImplementedError { message: String },
+    LoadError { message: String },
+    SyntaxError { message: String },
+    SystemCallError { message: String },
+    Errno { errno: i32, message: String },
+    Exception(AnyObject, String),
 }
 
 impl Error {
+    /// Creates an error from an exception object raised by Ruby.
+    ///
+    /// The message of the exception is retrieved using `Exception#message`. The name of the class
+    /// of the exception is used if `message` raises an exception or does not return a `String`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ruru::result::Error;
+    /// use ruru::{Class, Object, RString, VM};
+    /// # VM::init();
+    ///
+    /// let message = RString::new("Something went wrong").to_any_object();
+    /// let exception = Class::from_existing("RuntimeError").new_instance(Some(&[message]));
+    ///
+    /// let error = Error::from_exception(exception);
+    ///
+    /// assert_eq!(error.to_exception(), Class::from_existing("RuntimeError"));
+    /// assert_eq!(error.to_string(), "Something went wrong");
+    ///
+    /// let code = RString::new(
+    ///     "class BrokenMessageError < StandardError; def message; raise 'Broken'; end; end"
+    /// );
+    ///
+    /// Class::from_existing("Object").protect_send("eval", &[code.to_any_object()]).unwrap();
+    ///
+    /// let exception = Class::from_existing("BrokenMessageError").new_instance(None);
+    ///
+    /// assert_eq!(Error::from_exception(exception).to_string(), "BrokenMessageError");
+    /// ```
+    pub fn from_exception(exception: AnyObject) -> Self {
+        let value = exception.value();
+
+        // `message` of a user-defined exception may raise or return a non-`String`. An exception
+        // raised by `message` is not converted to `Error` to avoid infinite recursion
+        let message = vm::protect_value(|| binding_util::call_method(value, "message", None))
+            .ok()
+            .and_then(|message| AnyObject::from(message).try_convert_to::<RString>().ok())
+            .map(|message| message.to_string())
+            .unwrap_or_else(|| exception.class().name());
+
+        Error::Exception(exception, message)
+    }
+
+    /// Creates a `RuntimeError` from a payload of a caught Rust panic.
+    ///
+    /// The message is taken from the payload when it is a `&str` or a `String`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::panic;
+    ///
+    /// use ruru::result::Error;
+    ///
+    /// let payload = panic::catch_unwind(|| panic!("Something went wrong")).unwrap_err();
+    ///
+    /// assert_eq!(
+    ///     Error::from_panic(payload),
+    ///     Error::RuntimeError("Something went wrong".to_string())
+    /// );
+    /// ```
+    pub fn from_panic(payload: Box<Any + Send>) -> Self {
+        let message = if let Some(message) = payload.downcast_ref::<&str>() {
+            message.to_string()
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            "Rust panic".to_string()
+        };
+
+        Error::RuntimeError(message)
+    }
+
     /// Converts error to an exception class.
     ///
     /// # Examples
@@ -24,18 +115,68 @@ impl Error {
     /// # VM::init();
     /// let argument_error = Error::ArgumentError("Argument is missing".to_string());
     /// let type_error = Error::TypeError("Wrong type".to_string());
+    /// let range_error = Error::RangeError("Out of range".to_string());
+    /// let runtime_error = Error::RuntimeError("Something went wrong".to_string());
+    /// let key_error = Error::KeyError { message: "Key not found".to_string() };
     ///
     /// assert_eq!(argument_error.to_exception(), Class::from_existing("ArgumentError"));
     /// assert_eq!(type_error.to_exception(), Class::from_existing("TypeError"));
+    /// assert_eq!(range_error.to_exception(), Class::from_existing("RangeError"));
+    /// assert_eq!(runtime_error.to_exception(), Class::from_existing("RuntimeError"));
+    /// assert_eq!(key_error.to_exception(), Class::from_existing("KeyError"));
+    /// ```
+    ///
+    /// `Errno` is converted to the `Errno::*` class matching the given error number
+    ///
+    /// ```
+    /// use ruru::result::Error;
+    /// use ruru::{Class, VM};
+    ///
+    /// # VM::init();
+    /// // ENOENT
+    /// let errno = Error::Errno {
+    ///     errno: 2,
+    ///     message: "file.txt".to_string(),
+    /// };
+    ///
+    /// let expected_class = Class::from_existing("Errno").get_nested_class("ENOENT");
+    ///
+    /// assert_eq!(errno.to_exception(), expected_class);
     /// ```
     pub fn to_exception(&self) -> Class {
         let class_name = match *self {
             Error::ArgumentError(_) => "ArgumentError",
             Error::TypeError(_) => "TypeError",
+            Error::RuntimeError(_) => "RuntimeError",
+            Error::NoMethodError { .. } => "NoMethodError",
+            Error::NameError { .. } => "NameError",
+            Error::KeyError { .. } => "KeyError",
+            Error::IndexError { .. } => "IndexError",
+            Error::RangeError(_) => "RangeError",
+            Error::StopIteration { .. } => "StopIteration",
+            Error::NotImplementedError { .. } => "NotImplementedError",
+            Error::LoadError { .. } => "LoadError",
+            Error::SyntaxError { .. } => "SyntaxError",
+            Error::SystemCallError { .. } => "SystemCallError",
+            Error::Errno { errno, .. } => return Self::errno_class(errno),
+            Error::Exception(ref exception, _) => return exception.class(),
         };
 
         Class::from_existing(class_name)
     }
+
+    // `SystemCallError.new` returns an instance of the `Errno::*` class matching the `errno`
+    // or an instance of `SystemCallError` itself for unknown numbers
+    fn errno_class(errno: i32) -> Class {
+        let arguments = [
+            RString::new("").to_any_object(),
+            Fixnum::new(errno as i64).to_any_object(),
+        ];
+
+        Class::from_existing("SystemCallError")
+            .new_instance(Some(&arguments))
+            .class()
+    }
 }
 
 impl Display for Error {
@@ -47,7 +188,88 @@ impl Display for Error {
 impl error::Error for Error {
     fn description(&self) -> &str {
         match *self {
-            Error::ArgumentError(ref message) | Error::TypeError(ref message) => message,
+            Error::ArgumentError(ref message) |
+            Error::TypeError(ref message) |
+            Error::RuntimeError(ref message) |
+            Error::NoMethodError { ref message } |
+            Error::NameError { ref message } |
+            Error::KeyError { ref message } |
+            Error::IndexError { ref message } |
+            Error::RangeError(ref message) |
+            Error::StopIteration { ref message } |
+            Error::NotImplementedError { ref message } |
+            Error::LoadError { ref message } |
+            Error::SyntaxError { ref message } |
+            Error::SystemCallError { ref message } |
+            Error::Errno { ref message, .. } |
+            Error::Exception(_, ref message) => message,
+        }
+    }
+}
+
+/// Converts the result of a method defined with `methods!` macro to a Ruby object
+///
+/// It is implemented for all types which implement `Object` trait and for `Result<T, Error>`
+/// where `T: Object`. When the method returns `Err`, the error is raised as a Ruby exception
+/// (see `Error::to_exception()`).
+///
+/// This trait is used by `methods!` macro, there is no need to use it directly.
+pub trait ReturnValue {
+    fn into_return_value(self) -> AnyObject;
+}
+
+impl<T: Object> ReturnValue for T {
+    fn into_return_value(self) -> AnyObject {
+        self.to_any_object()
+    }
+}
+
+impl<T: Object> ReturnValue for Result<T> {
+    fn into_return_value(self) -> AnyObject {
+        match self {
+            Ok(object) => object.to_any_object(),
+            Err(error) => {
+                VM::raise_error(error);
+
+                // `VM::raise()` does not return, the control is passed back to Ruby
+                unreachable!()
+            }
+        }
+    }
+}
+
+/// Converts an argument of a method defined with `methods!` macro to its declared type
+///
+/// It is implemented for all types which implement `VerifiedObject` trait and for `Option<T>`
+/// where `T: VerifiedObject`. Arguments of type `Option<T>` are optional, they are `None` when
+/// the argument is not given or is `nil`.
+///
+/// This trait is used by `methods!` macro, there is no need to use it directly.
+pub trait MethodArgument: Sized {
+    /// Returns the value of a missing argument, `None` if the argument is required
+    fn missing() -> Option<Self> {
+        None
+    }
+
+    fn convert(argument: &AnyObject) -> Result<Self>;
+}
+
+impl<T: VerifiedObject> MethodArgument for T {
+    fn convert(argument: &AnyObject) -> Result<T> {
+        argument.try_convert_to::<T>()
+    }
+}
+
+impl<T: VerifiedObject> MethodArgument for Option<T> {
+    fn missing() -> Option<Self> {
+        Some(None)
+    }
+
+    fn convert(argument: &AnyObject) -> Result<Self> {
+        if argument.is_nil() {
+            Ok(None)
+        } else {
+            argument.try_convert_to::<T>().map(Some)
         }
     }
 }
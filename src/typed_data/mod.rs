@@ -1,6 +1,13 @@
 mod data_type_wrapper;
 
-use types::c_void;
+use std::cmp::Ordering;
+use std::panic::{self, AssertUnwindSafe};
+
+use binding::class;
+use result::Error;
+use types::{c_void, Argc, Value};
+
+use {AnyObject, Class, Fixnum, NilClass, Object, VM};
 
 pub use self::data_type_wrapper::DataTypeWrapper;
 
@@ -8,3 +15,308 @@ pub extern "C" fn free<T: Sized>(data: *mut c_void) {
     // Memory is freed when the box goes out of the scope
     unsafe { Box::from_raw(data as *mut T) };
 }
+
+/// Defines `<=>` method for a class which wraps Rust structs implementing `Ord`.
+///
+/// `<=>` returns `-1`, `0` or `1` according to `Ord::cmp()` of the wrapped structs and `nil`
+/// if the other object does not wrap the same struct. Including `Comparable` module into the
+/// class also defines `<`, `<=`, `==`, `>`, `>=` and `between?`.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate ruru;
+/// #[macro_use] extern crate lazy_static;
+///
+/// use ruru::{AnyObject, Array, Boolean, Class, Fixnum, Module, Object, VM};
+/// use ruru::typed_data;
+///
+/// #[derive(PartialEq, Eq, PartialOrd, Ord)]
+/// pub struct Version {
+///     major: i64,
+///     minor: i64,
+/// }
+///
+/// wrappable_struct!(Version, VersionWrapper, VERSION_WRAPPER);
+///
+/// class!(RubyVersion);
+///
+/// methods!(
+///     RubyVersion,
+///     itself,
+///
+///     fn ruby_version_new(major: Fixnum, minor: Fixnum) -> AnyObject {
+///         let version = Version {
+///             major: major.unwrap().to_i64(),
+///             minor: minor.unwrap().to_i64(),
+///         };
+///
+///         Class::from_existing("RubyVersion").wrap_data(version, &*VERSION_WRAPPER)
+///     }
+///
+///     fn ruby_version_minor() -> Fixnum {
+///         Fixnum::new(itself.get_data(&*VERSION_WRAPPER).minor)
+///     }
+/// );
+///
+/// fn main() {
+///     # VM::init();
+///     let data_class = Class::from_existing("Data");
+///
+///     Class::new("RubyVersion", Some(&data_class)).define(|itself| {
+///         itself.def_self("new", ruby_version_new);
+///         itself.def("minor", ruby_version_minor);
+///
+///         typed_data::define_comparison(itself, &*VERSION_WRAPPER);
+///
+///         itself
+///             .protect_send("include", &[Module::from_existing("Comparable").to_any_object()])
+///             .unwrap();
+///     });
+///
+///     let version = |major: i64, minor: i64| {
+///         let major = Fixnum::new(major).to_any_object();
+///         let minor = Fixnum::new(minor).to_any_object();
+///
+///         Class::from_existing("RubyVersion").protect_send("new", &[major, minor]).unwrap()
+///     };
+///
+///     let versions = Array::new().push(version(2, 4)).push(version(1, 9)).push(version(2, 3));
+///     let sorted = versions.sort();
+///
+///     let minors: Vec<i64> = sorted
+///         .into_iter()
+///         .map(|version| version.protect_send("minor", &[]).unwrap())
+///         .map(|minor| minor.try_convert_to::<Fixnum>().unwrap().to_i64())
+///         .collect();
+///
+///     assert_eq!(minors, vec![9, 3, 4]);
+///
+///     let result = version(1, 9).protect_send(">", &[version(2, 3)]).unwrap();
+///
+///     assert_eq!(result.try_convert_to::<Boolean>(), Ok(Boolean::new(false)));
+///
+///     let error = version(1, 9).protect_send("<=>", &[]).unwrap_err();
+///
+///     assert_eq!(error.to_exception(), Class::from_existing("ArgumentError"));
+/// }
+/// ```
+///
+/// Ruby:
+///
+/// ```ruby
+/// class RubyVersion
+///   include Comparable
+///
+///   attr_reader :major, :minor
+///
+///   def <=>(other)
+///     return nil unless other.is_a?(RubyVersion)
+///
+///     [major, minor] <=> [other.major, other.minor]
+///   end
+/// end
+///
+/// versions = [RubyVersion.new(2, 4), RubyVersion.new(1, 9), RubyVersion.new(2, 3)]
+///
+/// versions.sort.map(&:minor) == [9, 3, 4]
+/// (RubyVersion.new(1, 9) > RubyVersion.new(2, 3)) == false
+/// ```
+pub fn define_comparison<T>(class: &mut Class, wrapper: &'static DataTypeWrapper<T>)
+where
+    T: Ord + 'static,
+{
+    class::register_data_type(class.value(), wrapper);
+    class::define_method(class.value(), "<=>", compare::<T>);
+}
+
+// The data type is registered for the class of `itself` by `define_comparison()`
+extern "C" fn compare<T: Ord>(argc: Argc, argv: *const AnyObject, itself: AnyObject) -> AnyObject {
+    if argc != 1 {
+        let message = format!("wrong number of arguments (given {}, expected 1)", argc);
+
+        VM::raise_error(Error::ArgumentError(message));
+
+        unreachable!()
+    }
+
+    let data_type = match class::find_data_type(class::object_class(itself.value())) {
+        Some(data_type) => data_type,
+        None => {
+            VM::raise_error(Error::TypeError("Wrapped struct is not defined".to_string()));
+
+            unreachable!()
+        }
+    };
+
+    let other = VM::parse_arguments(argc, argv).remove(0);
+
+    if !class::is_typed_data_of(other.value(), data_type) {
+        return NilClass::new().to_any_object();
+    }
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let itself: &T = class::get_typed_data(itself.value(), data_type);
+        let other: &T = class::get_typed_data(other.value(), data_type);
+
+        itself.cmp(other)
+    }));
+
+    let result = match result {
+        Ok(Ordering::Less) => -1,
+        Ok(Ordering::Equal) => 0,
+        Ok(Ordering::Greater) => 1,
+        Err(payload) => {
+            VM::raise_error(Error::from_panic(payload));
+
+            // `VM::raise()` does not return, the control is passed back to Ruby
+            unreachable!()
+        }
+    };
+
+    Fixnum::new(result).to_any_object()
+}
+
+/// Defines `each` method for a class which wraps Rust structs.
+///
+/// `each` calls `iterator` with the wrapped struct and yields every item of the returned iterator
+/// to the given block. Without a block `each` returns an `Enumerator`. Including `Enumerable`
+/// module into the class also defines `map`, `select`, `reduce` and the rest of its methods.
+///
+/// The items are yielded one by one while the iterator is advanced, so infinite iterators work
+/// with methods which stop early (for example, `first` or `find`). The iterator is dropped even if
+/// the block breaks the iteration or raises an exception.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate ruru;
+/// #[macro_use] extern crate lazy_static;
+///
+/// use ruru::{AnyObject, Array, Class, Fixnum, Module, Object, RString, VM};
+/// use ruru::typed_data;
+///
+/// pub struct Counter {
+///     limit: i64,
+/// }
+///
+/// wrappable_struct!(Counter, CounterWrapper, COUNTER_WRAPPER);
+///
+/// class!(RubyCounter);
+///
+/// methods!(
+///     RubyCounter,
+///     itself,
+///
+///     fn ruby_counter_new(limit: Fixnum) -> AnyObject {
+///         let counter = Counter { limit: limit.unwrap().to_i64() };
+///
+///         Class::from_existing("RubyCounter").wrap_data(counter, &*COUNTER_WRAPPER)
+///     }
+/// );
+///
+/// fn main() {
+///     # VM::init();
+///     let data_class = Class::from_existing("Data");
+///
+///     Class::new("RubyCounter", Some(&data_class)).define(|itself| {
+///         itself.def_self("new", ruby_counter_new);
+///
+///         typed_data::define_each(itself, &*COUNTER_WRAPPER, |counter: &Counter| {
+///             (0..counter.limit).map(Fixnum::new)
+///         });
+///
+///         itself
+///             .protect_send("include", &[Module::from_existing("Enumerable").to_any_object()])
+///             .unwrap();
+///     });
+///
+///     let code = RString::new("RubyCounter.new(3).map { |number| number * 2 }");
+///     let result = Class::from_existing("Object")
+///         .protect_send("eval", &[code.to_any_object()])
+///         .unwrap();
+///     let result = result.try_convert_to::<Array>().unwrap();
+///
+///     let numbers: Vec<i64> = result
+///         .into_iter()
+///         .map(|number| number.try_convert_to::<Fixnum>().unwrap().to_i64())
+///         .collect();
+///
+///     assert_eq!(numbers, vec![0, 2, 4]);
+///
+///     let code = RString::new("RubyCounter.new(3).find { |number| number > 0 }");
+///     let result = Class::from_existing("Object")
+///         .protect_send("eval", &[code.to_any_object()])
+///         .unwrap();
+///
+///     assert_eq!(result.try_convert_to::<Fixnum>(), Ok(Fixnum::new(1)));
+///
+///     // Without a limit the iterator is infinite, only the needed items are produced
+///     Class::new("RubyNaturals", Some(&data_class)).define(|itself| {
+///         typed_data::define_each(itself, &*COUNTER_WRAPPER, |_: &Counter| {
+///             (1..).map(Fixnum::new)
+///         });
+///     });
+///
+///     let naturals: AnyObject = Class::from_existing("RubyNaturals")
+///         .wrap_data(Counter { limit: 0 }, &*COUNTER_WRAPPER);
+///     let first = naturals
+///         .protect_send("each", &[])
+///         .unwrap()
+///         .protect_send("first", &[Fixnum::new(2).to_any_object()])
+///         .unwrap();
+///
+///     assert_eq!(first.inspect(), "[1, 2]");
+/// }
+/// ```
+///
+/// Ruby:
+///
+/// ```ruby
+/// class RubyCounter
+///   include Enumerable
+///
+///   def initialize(limit)
+///     @limit = limit
+///   end
+///
+///   def each
+///     return enum_for(:each) unless block_given?
+///
+///     (0...@limit).each { |number| yield number }
+///
+///     self
+///   end
+/// end
+///
+/// RubyCounter.new(3).map { |number| number * 2 } == [0, 2, 4]
+/// RubyCounter.new(3).find { |number| number > 0 } == 1
+///
+/// class RubyNaturals
+///   def each
+///     return enum_for(:each) unless block_given?
+///
+///     (1..Float::INFINITY).each { |number| yield number }
+///   end
+/// end
+///
+/// RubyNaturals.new.each.first(2) == [1, 2]
+/// ```
+pub fn define_each<T, F, I>(class: &mut Class, wrapper: &'static DataTypeWrapper<T>, iterator: F)
+where
+    T: 'static,
+    F: Fn(&T) -> I + 'static,
+    I: IntoIterator,
+    I::IntoIter: 'static,
+    I::Item: Object,
+{
+    let items: class::EachItems = Box::new(move |itself: Value| {
+        let items = iterator(class::get_data(itself, wrapper))
+            .into_iter()
+            .map(|item| item.value());
+
+        Box::new(items) as Box<Iterator<Item = Value>>
+    });
+
+    class::define_each_method(class.value(), items);
+}
@@ -1,10 +1,15 @@
-use AnyObject;
+use {AnyObject, Class};
 
 pub use ruby_sys::types::{c_char, c_int, c_long, c_void, size_t, Argc, CallbackMutPtr,
                           CallbackPtr, Id, InternalValue, RbDataType as DataType,
                           RbDataTypeFunction as DataTypeFunction, SignedValue, Value, ValueType};
 
+/// Type of a Ruby object (`T_*` constants in MRI), the same type as `ValueType`
+pub use ruby_sys::types::ValueType as RubyType;
+
 #[cfg(unix)]
 pub use ruby_sys::types::RawFd;
 
 pub type Callback<I, O> = extern "C" fn(Argc, *const AnyObject, I) -> O;
+
+pub type AllocCallback<O> = extern "C" fn(Class) -> O;
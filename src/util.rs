@@ -2,9 +2,10 @@ use std::ffi::{CStr, CString};
 use std::ptr;
 
 use binding::global::RubySpecialConsts;
+use result::{Error, Result};
 use types::{c_char, c_int, c_void, Argc, InternalValue, Value};
 
-use {AnyObject, Object};
+use {AnyObject, Object, RString};
 
 pub unsafe fn cstr_to_string(str: *const c_char) -> String {
     CStr::from_ptr(str).to_string_lossy().into_owned()
@@ -18,6 +19,49 @@ pub fn str_to_cstring(str: &str) -> CString {
     CString::new(str).unwrap()
 }
 
+pub fn check_instance_variable_name(name: &str) -> Result<()> {
+    let mut chars = name.chars();
+
+    let is_valid = chars.next() == Some('@') &&
+                   chars.next().map_or(false, |c| c == '_' || c.is_alphabetic());
+
+    if is_valid {
+        Ok(())
+    } else {
+        let message = format!("'{}' is not allowed as an instance variable name", name);
+
+        Err(Error::NameError { message: message })
+    }
+}
+
+pub fn check_class_variable_name(name: &str) -> Result<()> {
+    let is_valid = name.starts_with("@@") &&
+                   name[2..].chars().next().map_or(false, |c| c == '_' || c.is_alphabetic());
+
+    if is_valid {
+        Ok(())
+    } else {
+        let message = format!("'{}' is not allowed as a class variable name", name);
+
+        Err(Error::NameError { message: message })
+    }
+}
+
+pub fn check_constant_name(name: &str) -> Result<()> {
+    let mut chars = name.chars();
+
+    let is_valid = chars.next().map_or(false, |c| c.is_uppercase()) &&
+                   chars.all(|c| c == '_' || c.is_alphanumeric());
+
+    if is_valid {
+        Ok(())
+    } else {
+        let message = format!("'{}' is not allowed as a constant name", name);
+
+        Err(Error::ArgumentError(message))
+    }
+}
+
 pub fn bool_to_value(state: bool) -> Value {
     let internal_value = if state {
         RubySpecialConsts::True
@@ -42,6 +86,16 @@ pub fn arguments_to_values(arguments: Option<&[AnyObject]>) -> Option<Vec<Value>
     arguments.map(|arguments| arguments.iter().map(Object::value).collect())
 }
 
+pub fn protected_string_representation<T: Object>(object: &T, method: &str) -> String {
+    object
+        .protect_send(method, &[])
+        .and_then(|result| result.try_convert_to::<RString>())
+        .map(|result| String::from_utf8_lossy(result.to_bytes()).into_owned())
+        .unwrap_or_else(|_| {
+            format!("#<{}:0x{:016x}>", object.class().name(), object.value().value)
+        })
+}
+
 pub fn process_arguments(arguments: &Option<Vec<Value>>) -> (Argc, *const Value) {
     match *arguments {
         Some(ref arguments) => (arguments.len() as Argc, arguments.as_ptr()),